@@ -0,0 +1,113 @@
+use iced::time::{Duration, Instant};
+use iced::widget::{center, column, container, text};
+use iced::{window, Element, Length, Point, Subscription, Task, Theme};
+use iced_mouse_layer::cursor_presence;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Frame(Instant),
+}
+
+/// A remote user, moved by a local simulation instead of the network.
+struct Peer {
+    name: &'static str,
+    speed: f32,
+    radius: f32,
+    /// The peer pauses for this long out of every 10 seconds, going stale.
+    pause: Duration,
+}
+
+const PEERS: [Peer; 3] = [
+    Peer { name: "Ada", speed: 0.9, radius: 140.0, pause: Duration::ZERO },
+    Peer { name: "Grace", speed: -0.6, radius: 90.0, pause: Duration::from_secs(4) },
+    Peer { name: "Linus", speed: 1.4, radius: 200.0, pause: Duration::from_secs(7) },
+];
+
+struct App {
+    start: Instant,
+    last_update: Instant,
+    positions: Vec<Point>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self {
+            start: now,
+            last_update: now,
+            positions: vec![Point::new(240.0, 200.0); PEERS.len()],
+        }
+    }
+}
+
+impl App {
+    fn title(&self) -> String {
+        "iced-mouse-layer cursor presence".into()
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let Message::Frame(now) = message;
+
+        // Positions arrive a few times per second, like they would over the network.
+        if now.duration_since(self.last_update) < Duration::from_millis(250) {
+            return Task::none();
+        }
+
+        self.last_update = now;
+
+        let elapsed = now.duration_since(self.start);
+        let cycle = Duration::from_secs(10);
+
+        for (peer, position) in PEERS.iter().zip(&mut self.positions) {
+            let in_cycle = Duration::from_secs_f32(elapsed.as_secs_f32() % cycle.as_secs_f32());
+
+            if in_cycle + peer.pause >= cycle {
+                continue;
+            }
+
+            let angle = elapsed.as_secs_f32() * peer.speed;
+
+            *position = Point::new(
+                320.0 + peer.radius * angle.cos(),
+                240.0 + peer.radius * angle.sin(),
+            );
+        }
+
+        Task::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let document = container(center(text(
+            "Other users' cursors glide between updates and fade out when they stop.",
+        )))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(container::bordered_box);
+
+        let cursors = PEERS.iter().zip(&self.positions).map(|(peer, position)| {
+            // Fading reaches the inherited text color, so the badge has no background of its own.
+            let label: Element<'_, Message> = container(column![text("▲").size(12), text(peer.name).size(12)])
+                .padding(4)
+                .into();
+
+            (peer.name, *position, label)
+        });
+
+        container(cursor_presence(document, cursors).stale_after(Duration::from_secs(2)))
+            .padding(16)
+            .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(App::default, App::update, App::view)
+        .title(App::title)
+        .subscription(App::subscription)
+        .theme(Theme::Dark)
+        .run()
+}
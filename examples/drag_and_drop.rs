@@ -0,0 +1,93 @@
+use iced::widget::{column, container, row, text};
+use iced::{Element, Length, Task, Theme};
+use iced_mouse_layer::{drag_source, drop_target, elevated, mouse_layer, Anchor};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Hovered(Option<usize>),
+    Dropped(usize, &'static str),
+    Cancelled,
+}
+
+#[derive(Default)]
+struct App {
+    bins: [Vec<&'static str>; 2],
+    hovered: Option<usize>,
+    status: String,
+}
+
+const FRUITS: [&str; 4] = ["🍎 Apple", "🍌 Banana", "🍇 Grapes", "🍑 Peach"];
+
+impl App {
+    fn title(&self) -> String {
+        "iced-mouse-layer drag and drop".into()
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Hovered(bin) => self.hovered = bin,
+            Message::Dropped(bin, fruit) => {
+                self.hovered = None;
+                self.bins[bin].push(fruit);
+                self.status = format!("Dropped {fruit} into bin {}", bin + 1);
+            }
+            Message::Cancelled => {
+                self.hovered = None;
+                self.status = "Cancelled".into();
+            }
+        }
+
+        Task::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let fruits = column(FRUITS.into_iter().map(|fruit| {
+            drag_source(container(text(fruit)).padding(8), fruit)
+                .ghost(
+                    mouse_layer(container(text(fruit)).padding(8))
+                        .anchor(Anchor::CENTER)
+                        .style(elevated),
+                )
+                .on_cancel(Message::Cancelled)
+                .into()
+        }))
+        .spacing(4);
+
+        let bins = row((0..2).map(|bin| {
+            let label = if self.hovered == Some(bin) {
+                format!("Bin {} — release to drop", bin + 1)
+            } else {
+                format!("Bin {}", bin + 1)
+            };
+
+            drop_target(
+                container(column![text(label), text(self.bins[bin].join(", ")).size(12)])
+                    .padding(16)
+                    .width(Length::Fill)
+                    .height(Length::Fixed(160.0))
+                    .style(container::bordered_box),
+            )
+            .on_enter(move |_: &'static str| Message::Hovered(Some(bin)))
+            .on_leave(Message::Hovered(None))
+            .on_drop(move |fruit| Message::Dropped(bin, fruit))
+            .into()
+        }))
+        .spacing(16);
+
+        column![
+            text("Drag a fruit into a bin. Press Escape to cancel."),
+            row![fruits, bins].spacing(24),
+            text(&self.status),
+        ]
+        .spacing(16)
+        .padding(16)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application(App::default, App::update, App::view)
+        .title(App::title)
+        .theme(Theme::Dark)
+        .run()
+}
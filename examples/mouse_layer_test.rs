@@ -6,7 +6,8 @@ use iced::{
     Element, Length, Task, Theme,
 };
 
-use iced_mouse_layer::mouse_layer;
+use iced_mouse_layer::{follow_tooltip, mouse_layer, Anchor, Follow, HitTest, Placement};
+use std::time::Duration;
 
 pub fn main() -> iced::Result {
     iced::application(App::default, App::update, App::view)
@@ -24,6 +25,11 @@ enum Message {
     SliderChanged(f32),
 
     SelectGhost(GhostKind),
+    SelectPlacement(Placement),
+    ToggleInteractive(bool),
+    SelectFollow(Follow),
+    CursorMoved(iced::Point),
+    CursorLeft,
 
     OffsetXChanged(f32),
     OffsetYChanged(f32),
@@ -62,6 +68,10 @@ struct App {
     slider_v: f32,
 
     ghost_kind: GhostKind,
+    placement: Placement,
+    interactive: bool,
+    follow: Follow,
+    cursor: Option<iced::Point>,
     offset_x: f32,
     offset_y: f32,
     offset_x_text: String,
@@ -76,6 +86,10 @@ impl Default for App {
             slider_v: 0.3,
 
             ghost_kind: GhostKind::Badge,
+            placement: Placement::Free,
+            interactive: false,
+            follow: Follow::Snap,
+            cursor: None,
             offset_x: 15.0,
             offset_y: 15.0,
             offset_x_text: "15".into(),
@@ -98,6 +112,11 @@ impl App {
             Message::SliderChanged(v) => self.slider_v = v,
 
             Message::SelectGhost(k) => self.ghost_kind = k,
+            Message::SelectPlacement(p) => self.placement = p,
+            Message::ToggleInteractive(v) => self.interactive = v,
+            Message::SelectFollow(f) => self.follow = f,
+            Message::CursorMoved(p) => self.cursor = Some(p),
+            Message::CursorLeft => self.cursor = None,
 
             Message::OffsetXChanged(v) => {
                 self.offset_x = v;
@@ -168,9 +187,20 @@ impl App {
         let demo = column![
             text("Playground").size(18),
             text("Click / type / scroll; ghost should keep following.").size(12),
+            text(match self.cursor {
+                Some(p) => format!("Cursor: {:.0}, {:.0}", p.x, p.y),
+                None => "Cursor: outside".into(),
+            })
+            .size(12),
             Space::new().height(Length::Fixed(8.0)),
             row![
-                button("Button A").on_press(Message::ClickedA),
+                follow_tooltip(
+                    button("Button A").on_press(Message::ClickedA),
+                    container(text("Follow tooltip").size(12))
+                        .padding(6)
+                        .style(container::rounded_box),
+                )
+                .show_delay(Duration::from_millis(300)),
                 Space::new().width(Length::Fixed(8.0)),
                 button("Button B").on_press(Message::ClickedB),
             ]
@@ -219,6 +249,32 @@ impl App {
         ]
         .spacing(6);
 
+        let placement_list = column![
+            text("Placement").size(16),
+            radio("Free", Placement::Free, Some(self.placement), Message::SelectPlacement),
+            radio("Clamp", Placement::Clamp, Some(self.placement), Message::SelectPlacement),
+            radio("Flip", Placement::Flip, Some(self.placement), Message::SelectPlacement),
+            radio("Slide", Placement::Slide, Some(self.placement), Message::SelectPlacement),
+            Space::new().height(Length::Fixed(8.0)),
+            checkbox(self.interactive)
+                .label("Interactive (centered on cursor)")
+                .on_toggle(Message::ToggleInteractive),
+        ]
+        .spacing(6);
+
+        let spring = Follow::Spring(Duration::from_millis(80));
+        let exponential = Follow::Exponential(Duration::from_millis(80));
+        let lag = Follow::Lag(Duration::from_millis(250));
+
+        let follow_list = column![
+            text("Follow").size(16),
+            radio("Snap", Follow::Snap, Some(self.follow), Message::SelectFollow),
+            radio("Spring", spring, Some(self.follow), Message::SelectFollow),
+            radio("Exponential", exponential, Some(self.follow), Message::SelectFollow),
+            radio("Lag", lag, Some(self.follow), Message::SelectFollow),
+        ]
+        .spacing(6);
+
         let offset_controls = column![
             text("Offset").size(16),
             row![
@@ -251,11 +307,17 @@ impl App {
         ]
         .spacing(6);
 
-        let controls = column![text("Controls").size(18), radio_list, offset_controls]
-            .spacing(12)
-            .padding(12);
+        let controls = column![
+            text("Controls").size(18),
+            radio_list,
+            placement_list,
+            follow_list,
+            offset_controls,
+        ]
+        .spacing(12)
+        .padding(12);
 
-        let controls_panel = container(controls)
+        let controls_panel = container(scrollable(controls))
             .width(Length::FillPortion(1))
             .height(Length::Fill);
 
@@ -417,7 +479,15 @@ impl App {
             }
         };
 
-        let ghost = mouse_layer(ghost_content).offset(self.offset_x, self.offset_y);
+        let ghost = mouse_layer(ghost_content)
+            .offset(self.offset_x, self.offset_y)
+            .placement(self.placement)
+            .anchor(if self.interactive { Anchor::CENTER } else { Anchor::TOP_LEFT })
+            .interactive(self.interactive)
+            .hit_test(HitTest::CaptureWhenInteractive)
+            .follow(self.follow)
+            .on_move(|position, _| Message::CursorMoved(position))
+            .on_leave_window(Message::CursorLeft);
 
         column![base, ghost].height(Length::Fill).into()
     }
@@ -0,0 +1,142 @@
+//! Guide lines through the cursor.
+use iced::advanced::renderer;
+use iced::{Color, Element, Point, Rectangle, Size};
+
+use crate::{Catalog, MouseLayer, Placement};
+
+/// Guide lines drawn through the cursor across the area of a [`MouseLayer`].
+///
+/// The lines span the region of the layer if it is shown [`within`](MouseLayer::within)
+/// an element, and the viewport otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crosshair {
+    color: Color,
+    thickness: f32,
+    dash: Option<(f32, f32)>,
+    axes: Axes,
+}
+
+/// The guide lines drawn by a [`Crosshair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axes {
+    /// A horizontal line.
+    Horizontal,
+    /// A vertical line.
+    Vertical,
+    /// Both lines. This is the default.
+    #[default]
+    Both,
+}
+
+impl Crosshair {
+    /// Creates a solid [`Crosshair`] of the given color, one pixel thick.
+    pub fn new(color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into(),
+            thickness: 1.0,
+            dash: None,
+            axes: Axes::default(),
+        }
+    }
+
+    /// Sets the thickness of the lines.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Dashes the lines with dashes and gaps of the given lengths.
+    pub fn dash(mut self, dash: f32, gap: f32) -> Self {
+        self.dash = Some((dash, gap));
+        self
+    }
+
+    /// Sets which lines are drawn.
+    pub fn axes(mut self, axes: Axes) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Draws the lines through `cursor` across `bounds`.
+    pub(crate) fn draw<Renderer>(&self, renderer: &mut Renderer, cursor: Point, bounds: Rectangle, alpha: f32)
+    where
+        Renderer: iced::advanced::Renderer,
+    {
+        let color = self.color.scale_alpha(alpha);
+        let half = self.thickness / 2.0;
+
+        if matches!(self.axes, Axes::Horizontal | Axes::Both) {
+            for (start, length) in self.dashes(bounds.width) {
+                fill(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(bounds.x + start, cursor.y - half),
+                        Size::new(length, self.thickness),
+                    ),
+                    color,
+                );
+            }
+        }
+
+        if matches!(self.axes, Axes::Vertical | Axes::Both) {
+            for (start, length) in self.dashes(bounds.height) {
+                fill(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(cursor.x - half, bounds.y + start),
+                        Size::new(self.thickness, length),
+                    ),
+                    color,
+                );
+            }
+        }
+    }
+
+    /// Returns the start and length of every dash along a line of the given length.
+    fn dashes(&self, length: f32) -> impl Iterator<Item = (f32, f32)> {
+        let (dash, gap) = self.dash.unwrap_or((length, 0.0));
+        let period = (dash + gap).max(1.0);
+        let count = (length / period).ceil().min(4096.0) as usize;
+
+        (0..count).map(move |index| {
+            let start = index as f32 * period;
+
+            (start, dash.min(length - start))
+        })
+    }
+}
+
+impl Default for Crosshair {
+    fn default() -> Self {
+        Self::new(Color::from_rgba(0.5, 0.5, 0.5, 0.8))
+    }
+}
+
+fn fill<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: iced::advanced::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            snap: true,
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+}
+
+/// Creates a new [`MouseLayer`] drawing the default [`Crosshair`] through the cursor,
+/// with the given label next to it, e.g. a coordinate readout.
+pub fn crosshair<'a, Message, Theme, Renderer>(
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> MouseLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    MouseLayer::new(label)
+        .guides(Crosshair::default())
+        .offset(8.0, 8.0)
+        .placement(Placement::Flip)
+}
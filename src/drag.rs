@@ -0,0 +1,806 @@
+//! Drag and drop built on top of the mouse layer.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::keyboard;
+use iced::{Element, Event, Length, Point, Rectangle, Size, Vector};
+
+use crate::{Catalog, MouseLayer, Status};
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    /// The drag in progress, shared by every [`DragSource`] and [`DropTarget`] of the thread.
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+
+    /// The identifier of the next drag.
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+struct Session {
+    /// The drag of the [`DragSource`] that started the session.
+    id: u64,
+    payload: Box<dyn Any>,
+    phase: Phase,
+    is_dropped: bool,
+    /// The number of [`DropTarget`]s under the cursor.
+    hovered: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// The button is held.
+    Dragging,
+    /// The button was just released; targets may still accept the payload.
+    Released,
+    /// The drag is over, even if its source is gone and never ends it.
+    Ended,
+}
+
+/// Starts a new session for `payload` and returns its identifier.
+fn start(payload: Box<dyn Any>) -> u64 {
+    let id = NEXT_ID.get();
+    NEXT_ID.set(id.wrapping_add(1));
+
+    SESSION.set(Some(Session {
+        id,
+        payload,
+        phase: Phase::Dragging,
+        is_dropped: false,
+        hovered: 0,
+    }));
+
+    id
+}
+
+/// Advances the session with an [`Event`] seen by any [`DragSource`] or [`DropTarget`].
+///
+/// Every participant calls this, so a drag ends when the button is released, `Escape` is
+/// pressed or the cursor leaves the window, even if its source left the view.
+fn track(event: &Event) {
+    let is_release = matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    );
+
+    let is_cancel = matches!(
+        event,
+        Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            })
+    );
+
+    SESSION.with_borrow_mut(|session| {
+        if let Some(session) = session {
+            session.phase = match session.phase {
+                Phase::Dragging if is_release => Phase::Released,
+                Phase::Dragging if is_cancel => Phase::Ended,
+                // Every participant sees the release before the session ends.
+                Phase::Released if !is_release => Phase::Ended,
+                phase => phase,
+            };
+        }
+    });
+}
+
+/// Returns the [`Phase`] of the drag with the given identifier, if it is the current session.
+fn phase(id: u64) -> Option<Phase> {
+    SESSION.with_borrow(|session| {
+        session
+            .as_ref()
+            .filter(|session| session.id == id)
+            .map(|session| session.phase)
+    })
+}
+
+/// Removes the session of the drag with the given identifier and returns whether it was dropped.
+fn end(id: u64) -> bool {
+    SESSION.with_borrow_mut(|session| {
+        if session.as_ref().is_some_and(|session| session.id == id) {
+            session.take().is_some_and(|session| session.is_dropped)
+        } else {
+            false
+        }
+    })
+}
+
+/// Returns a copy of the payload being dragged, if it is a `Payload` and the drag is not over.
+fn dragged<Payload: Clone + 'static>() -> Option<Payload> {
+    SESSION.with_borrow(|session| {
+        session
+            .as_ref()
+            .filter(|session| session.phase != Phase::Ended)
+            .and_then(|session| session.payload.downcast_ref::<Payload>())
+            .cloned()
+    })
+}
+
+/// Returns whether the drag in progress is over a [`DropTarget`] accepting its payload.
+fn is_over_target() -> bool {
+    SESSION.with_borrow(|session| session.as_ref().is_some_and(|session| session.hovered > 0))
+}
+
+/// Adds `delta` to the number of [`DropTarget`]s under the cursor.
+fn hover_target(delta: isize) {
+    SESSION.with_borrow_mut(|session| {
+        if let Some(session) = session {
+            session.hovered = session.hovered.saturating_add_signed(delta);
+        }
+    });
+}
+
+/// An element that can be dragged onto a [`DropTarget`], carrying a `Payload`.
+///
+/// Pressing the left mouse button over the content and moving past the threshold
+/// starts a drag; releasing the button drops it and `Escape` cancels it.
+pub struct DragSource<'a, Payload, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    ghost: Option<MouseLayer<'a, Message, Theme, Renderer>>,
+    payload: Payload,
+    threshold: f32,
+    on_drag: Option<Message>,
+    on_drop: Option<Message>,
+    on_cancel: Option<Message>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer> DragSource<'a, Payload, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`DragSource`] with the given content and payload.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, payload: Payload) -> Self {
+        Self {
+            content: content.into(),
+            ghost: None,
+            payload,
+            threshold: 4.0,
+            on_drag: None,
+            on_drop: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the [`MouseLayer`] that follows the cursor while dragging.
+    pub fn ghost(mut self, ghost: MouseLayer<'a, Message, Theme, Renderer>) -> Self {
+        self.ghost = Some(ghost);
+        self
+    }
+
+    /// Sets the distance the cursor must travel with the button held before a drag starts.
+    /// Default is `4.0`.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the message produced when a drag starts.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the payload is dropped on a [`DropTarget`].
+    pub fn on_drop(mut self, message: Message) -> Self {
+        self.on_drop = Some(message);
+        self
+    }
+
+    /// Sets the message produced when a drag is cancelled with `Escape`, by the cursor
+    /// leaving the window, or by a release outside of any [`DropTarget`].
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Drag {
+    #[default]
+    Idle,
+    Pressed(Point),
+    Dragging(u64),
+}
+
+impl<Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragSource<'_, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Drag>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Drag::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        let mut children = vec![widget::Tree::new(&self.content)];
+
+        if let Some(ghost) = &self.ghost {
+            children.push(widget::Tree::new(ghost as &dyn Widget<_, _, _>));
+        }
+
+        children
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        match &self.ghost {
+            Some(ghost) => tree.diff_children(&[
+                self.content.as_widget(),
+                ghost as &dyn Widget<_, _, _>,
+            ]),
+            None => tree.diff_children(&[&self.content]),
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        track(event);
+
+        let drag = tree.state.downcast_mut::<Drag>();
+
+        match (*drag, event) {
+            (Drag::Dragging(id), _) if phase(id).is_none_or(|phase| phase == Phase::Ended) => {
+                let message = if end(id) {
+                    self.on_drop.clone()
+                } else {
+                    self.on_cancel.clone()
+                };
+
+                if let Some(message) = message {
+                    shell.publish(message);
+                }
+
+                if let Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) = event
+                {
+                    shell.capture_event();
+                }
+
+                *drag = Drag::Idle;
+                shell.request_redraw();
+            }
+            (Drag::Idle, Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))) => {
+                if !shell.is_event_captured()
+                    && let Some(position) = cursor.position_over(layout.bounds())
+                {
+                    *drag = Drag::Pressed(position);
+                }
+            }
+            (Drag::Pressed(origin), Event::Mouse(mouse::Event::CursorMoved { .. }))
+                if cursor
+                    .position()
+                    .is_some_and(|position| origin.distance(position) >= self.threshold) =>
+            {
+                let id = start(Box::new(self.payload.clone()));
+
+                if let Some(message) = self.on_drag.clone() {
+                    shell.publish(message);
+                }
+
+                *drag = Drag::Dragging(id);
+                shell.request_redraw();
+            }
+            (Drag::Pressed(_), Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))) => {
+                *drag = Drag::Idle;
+            }
+            (Drag::Dragging(_), Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))) => {
+                // The session ends on the next event, once every target has seen the release.
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let Drag::Dragging(id) = tree.state.downcast_ref::<Drag>()
+            && phase(*id) == Some(Phase::Dragging)
+        {
+            // The ghost may override the cursor of the drag.
+            let interaction = self
+                .ghost
+                .as_ref()
+                .zip(tree.children.get(1))
+                .map(|(ghost, tree)| {
+                    ghost.mouse_interaction(tree, layout, cursor, viewport, renderer)
+                })
+                .unwrap_or_default();
+
+            return if interaction == mouse::Interaction::None {
+                mouse::Interaction::Grabbing
+            } else {
+                interaction
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_dragging = match tree.state.downcast_ref::<Drag>() {
+            Drag::Dragging(id) => phase(*id) == Some(Phase::Dragging),
+            _ => false,
+        };
+
+        let (content_tree, ghost_tree) = match tree.children.as_mut_slice() {
+            [content, ghost] => (content, Some(ghost)),
+            [content, ..] => (content, None),
+            [] => return None,
+        };
+
+        let mut children: Vec<_> = self
+            .content
+            .as_widget_mut()
+            .overlay(content_tree, layout, renderer, viewport, translation)
+            .into_iter()
+            .collect();
+
+        if let Some((ghost, ghost_tree)) = self.ghost.as_mut().zip(ghost_tree) {
+            ghost.set_hidden(!is_dragging);
+            ghost.set_drag_status(is_dragging.then(|| {
+                if is_over_target() {
+                    Status::OverTarget
+                } else {
+                    Status::Dragging
+                }
+            }));
+
+            children.extend(ghost.overlay(ghost_tree, layout, renderer, viewport, translation));
+        }
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer> From<DragSource<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(source: DragSource<'a, Payload, Message, Theme, Renderer>) -> Self {
+        Element::new(source)
+    }
+}
+
+/// An element that accepts the `Payload` of a [`DragSource`] dropped on it.
+pub struct DropTarget<'a, Payload, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_enter: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(Payload, Point) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer> DropTarget<'a, Payload, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`DropTarget`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_enter: None,
+            on_hover: None,
+            on_leave: None,
+            on_drop: None,
+        }
+    }
+
+    /// Sets the message produced when a payload is dragged into the target.
+    pub fn on_enter(mut self, on_enter: impl Fn(Payload) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message produced when a payload is moved over the target.
+    pub fn on_hover(mut self, on_hover: impl Fn(Payload, Point) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the message produced when a payload leaves the target without being dropped.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the message produced when a payload is dropped on the target.
+    pub fn on_drop(mut self, on_drop: impl Fn(Payload) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Hover {
+    is_hovered: bool,
+}
+
+impl<Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'_, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Hover>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Hover::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        track(event);
+
+        let hover = tree.state.downcast_mut::<Hover>();
+        let payload = dragged::<Payload>();
+        let position = cursor.position_over(layout.bounds());
+
+        let (Some(payload), Some(position)) = (payload, position) else {
+            if hover.is_hovered {
+                hover.is_hovered = false;
+                hover_target(-1);
+
+                if let Some(message) = self.on_leave.clone() {
+                    shell.publish(message);
+                }
+            }
+
+            return;
+        };
+
+        if !hover.is_hovered {
+            hover.is_hovered = true;
+            hover_target(1);
+
+            if let Some(on_enter) = &self.on_enter {
+                shell.publish(on_enter(payload.clone()));
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(on_hover) = &self.on_hover {
+                    shell.publish(on_hover(payload, position));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                // Only one target receives the payload, even when targets are nested.
+                let is_first = SESSION.with_borrow_mut(|session| {
+                    session.as_mut().is_some_and(|session| {
+                        !std::mem::replace(&mut session.is_dropped, true)
+                    })
+                });
+
+                if is_first && let Some(on_drop) = &self.on_drop {
+                    shell.publish(on_drop(payload));
+                }
+
+                hover.is_hovered = false;
+                hover_target(-1);
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer> From<DropTarget<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(target: DropTarget<'a, Payload, Message, Theme, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
+
+/// Creates a new [`DragSource`].
+pub fn drag_source<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: Payload,
+) -> DragSource<'a, Payload, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    DragSource::new(content, payload)
+}
+
+/// Creates a new [`DropTarget`].
+pub fn drop_target<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, Payload, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    DropTarget::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn moved() -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        })
+    }
+
+    #[test]
+    fn targets_see_the_release_then_the_drag_ends() {
+        let id = start(Box::new(7_u32));
+
+        track(&release());
+        track(&release());
+        assert_eq!(phase(id), Some(Phase::Released));
+        assert_eq!(dragged::<u32>(), Some(7));
+
+        track(&moved());
+        assert_eq!(phase(id), Some(Phase::Ended));
+        assert_eq!(dragged::<u32>(), None);
+    }
+
+    #[test]
+    fn a_drag_without_its_source_ends() {
+        let _ = start(Box::new(7_u32));
+
+        track(&release());
+        track(&moved());
+        track(&release());
+
+        assert_eq!(dragged::<u32>(), None);
+    }
+
+    #[test]
+    fn leaving_the_window_cancels() {
+        let id = start(Box::new(7_u32));
+
+        track(&Event::Mouse(mouse::Event::CursorLeft));
+
+        assert_eq!(dragged::<u32>(), None);
+        assert!(!end(id));
+        assert_eq!(phase(id), None);
+    }
+
+    #[test]
+    fn only_the_current_drag_is_ended() {
+        let old = start(Box::new(1_u32));
+        let new = start(Box::new(2_u32));
+
+        assert!(!end(old));
+        assert_eq!(phase(new), Some(Phase::Dragging));
+        assert_eq!(dragged::<u32>(), Some(2));
+    }
+}
@@ -0,0 +1,174 @@
+//! Smoothed following of the cursor.
+use iced::time::{Duration, Instant};
+use iced::{Point, Vector};
+
+use std::collections::VecDeque;
+
+/// How the content of a [`MouseLayer`](crate::MouseLayer) catches up with the cursor.
+///
+/// Every mode other than [`Follow::Snap`] is driven by the timestamps of
+/// `window::Event::RedrawRequested`, so the motion does not depend on the frame rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Follow {
+    /// The content jumps to the cursor on every event.
+    #[default]
+    Snap,
+    /// The content is pulled by a critically damped spring. The duration is the
+    /// half-life: the time it takes to cover half of the remaining distance.
+    Spring(Duration),
+    /// The content moves a fixed fraction of the remaining distance per unit of time.
+    /// The duration is the half-life.
+    Exponential(Duration),
+    /// The content replays the path of the cursor, the given duration behind it.
+    Lag(Duration),
+}
+
+/// The animated position of the content, kept apart from the raw cursor position.
+#[derive(Debug, Default)]
+pub(crate) struct Motion {
+    position: Option<Point>,
+    velocity: Vector,
+    history: VecDeque<(Instant, Point)>,
+    last_frame: Option<Instant>,
+}
+
+impl Motion {
+    /// Returns the position the content should be displayed at.
+    pub fn position(&self) -> Option<Point> {
+        self.position
+    }
+
+    /// Moves the content straight to the `goal`, dropping any animation.
+    pub fn jump(&mut self, goal: Option<Point>) {
+        self.position = goal;
+        self.velocity = Vector::ZERO;
+        self.history.clear();
+        self.last_frame = None;
+    }
+
+    /// Advances the animation toward the `goal` to the frame at `now`.
+    ///
+    /// Returns `true` while the content has not caught up with the `goal`.
+    pub fn step(&mut self, follow: Follow, goal: Option<Point>, now: Instant) -> bool {
+        // Without a cursor, the content stays where it was last seen.
+        let Some(goal) = goal else {
+            return false;
+        };
+
+        let Some(position) = self.position else {
+            self.jump(Some(goal));
+            return false;
+        };
+
+        if self.last_frame == Some(now) {
+            return position != goal;
+        }
+
+        let last_frame = self.last_frame.replace(now);
+        let dt = last_frame
+            .map(|last_frame| now.saturating_duration_since(last_frame))
+            .unwrap_or_default();
+
+        match follow {
+            Follow::Snap => {
+                self.jump(Some(goal));
+                false
+            }
+            Follow::Spring(half_life) => {
+                let dt = dt.as_secs_f32();
+                let y = half_life_to_damping(half_life) / 2.0;
+                let j0 = position - goal;
+                let j1 = self.velocity + j0 * y;
+                let decay = (-y * dt).exp();
+
+                self.velocity = (self.velocity - j1 * (y * dt)) * decay;
+                self.settle(goal, goal + (j0 + j1 * dt) * decay)
+            }
+            Follow::Exponential(half_life) => {
+                let remaining =
+                    0.5_f32.powf(dt.as_secs_f32() / half_life.as_secs_f32().max(f32::EPSILON));
+
+                self.settle(goal, goal + (position - goal) * remaining)
+            }
+            Follow::Lag(lag) => {
+                self.record(goal, now, last_frame.filter(|_| dt <= lag));
+
+                let position = sample(&mut self.history, now.checked_sub(lag).unwrap_or(now));
+
+                self.position = Some(position);
+                position != goal
+            }
+        }
+    }
+
+    /// Moves to the new `position`, snapping to the `goal` once close enough.
+    fn settle(&mut self, goal: Point, position: Point) -> bool {
+        let is_settled = position.distance(goal) < 0.1
+            && self.velocity.x.abs() < 0.1
+            && self.velocity.y.abs() < 0.1;
+
+        if is_settled {
+            self.jump(Some(goal));
+        } else {
+            self.position = Some(position);
+        }
+
+        !is_settled
+    }
+
+    /// Records the cursor path for [`Follow::Lag`].
+    ///
+    /// `last_frame` is `None` when the animation was idle for longer than the lag.
+    fn record(&mut self, goal: Point, now: Instant, last_frame: Option<Instant>) {
+        let Some(last_frame) = last_frame else {
+            self.history.clear();
+            self.history.push_back((now, self.position.unwrap_or(goal)));
+            self.history.push_back((now, goal));
+            return;
+        };
+
+        let Some(&(time, point)) = self.history.back() else {
+            self.history.push_back((now, goal));
+            return;
+        };
+
+        if point == goal {
+            return;
+        }
+
+        // The cursor rested at `point` until the last frame before moving.
+        if time < last_frame {
+            self.history.push_back((last_frame, point));
+        }
+
+        self.history.push_back((now, goal));
+    }
+}
+
+/// Converts a half-life into the damping of a critically damped spring.
+fn half_life_to_damping(half_life: Duration) -> f32 {
+    (4.0 * std::f32::consts::LN_2) / half_life.as_secs_f32().max(f32::EPSILON)
+}
+
+/// Interpolates the recorded path of the cursor at the given time,
+/// forgetting the samples that are no longer needed.
+fn sample(history: &mut VecDeque<(Instant, Point)>, at: Instant) -> Point {
+    while history.len() > 1 && history[1].0 <= at {
+        let _ = history.pop_front();
+    }
+
+    let (start_time, start) = history[0];
+
+    let Some(&(end_time, end)) = history.get(1) else {
+        return start;
+    };
+
+    if at <= start_time {
+        return start;
+    }
+
+    let span = end_time.saturating_duration_since(start_time).as_secs_f32();
+    let t = at.saturating_duration_since(start_time).as_secs_f32() / span.max(f32::EPSILON);
+
+    start + (end - start) * t.min(1.0)
+}
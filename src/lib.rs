@@ -3,32 +3,94 @@
 //! # Example
 //!
 //! ```no_run
-//! use iced::widget::mouse_layer;
+//! use iced::widget::text;
+//! use iced_mouse_layer::{mouse_layer, Placement};
 //!
-//! let layer = mouse_layer(some_content)
+//! let layer: iced_mouse_layer::MouseLayer<'_, ()> = mouse_layer(text("Hello"))
 //!     .offset(10.0, 10.0)
-//!     .scale(0.9);
+//!     .scale(0.9)
+//!     .placement(Placement::Flip);
 //! ```
+mod crosshair;
+mod drag;
+mod follow;
+mod magnifier;
+mod presence;
+mod readout;
+mod stack;
+mod style;
+mod tooltip;
+mod touch;
+mod trail;
+mod transition;
+mod visibility;
+
+pub use crosshair::{crosshair, Axes, Crosshair};
+pub use drag::{drag_source, drop_target, DragSource, DropTarget};
+pub use follow::Follow;
+pub use magnifier::{magnifier, Lens, Magnifier};
+pub use presence::{cursor_presence, CursorPresence};
+pub use readout::{cursor_readout, CursorReadout};
+pub use stack::{mouse_layer_stack, MouseLayerStack};
+pub use style::{elevated, transparent, Catalog, Status, Style, StyleFn};
+pub use tooltip::{follow_tooltip, FollowTooltip};
+pub use touch::{finger_layer, FingerLayer, Touch};
+pub use trail::Trail;
+pub use transition::Transition;
+
+use follow::Motion;
+use stack::Stacking;
+use touch::Pointer;
+use trail::History;
+use transition::Appearance;
+use visibility::{Toggle, Visibility};
+
 use iced::advanced::layout::{self, Layout};
 use iced::advanced::mouse;
 use iced::advanced::overlay;
 use iced::advanced::renderer;
 use iced::advanced::widget::{self, Widget};
 use iced::advanced::{Clipboard, Shell};
-use iced::{Element, Length, Point, Rectangle, Size, Vector, Theme, Renderer,Event};
+use iced::time::{Duration, Instant};
+use iced::window;
+use iced::{Color, Element, Length, Pixels, Point, Rectangle, Size, Transformation, Vector, Theme, Renderer,Event};
 
 
 /// A widget that displays content following the mouse cursor.
 pub struct MouseLayer<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
     content: Element<'a, Message, Theme, Renderer>,
     offset: Vector,
+    placement: Placement,
+    scale: f32,
+    pivot: Vector,
+    opacity: f32,
+    tint: Option<Color>,
+    guides: Option<Crosshair>,
+    anchor: Anchor,
+    hotspot: Option<Vector>,
+    touch_placement: Option<(Vector, Anchor)>,
+    interactive: bool,
+    hit_test: HitTest,
+    replace_cursor: bool,
+    interaction: Option<Box<dyn Fn(Point) -> mouse::Interaction + 'a>>,
+    class: Theme::Class<'a>,
+    drag_status: Option<Status>,
+    z_index: i32,
+    stacking: Option<Stacking>,
+    region: Option<Element<'a, Message, Theme, Renderer>>,
+    bounded: bool,
+    sizing: Sizing,
+    behavior: Behavior,
+    callbacks: Callbacks<'a, Message>,
 }
 
 impl<'a, Message, Theme, Renderer> MouseLayer<'a, Message, Theme, Renderer>
 where
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
     /// Creates a new [`MouseLayer`] with the given content.
@@ -36,6 +98,28 @@ where
         Self {
             content: content.into(),
             offset: Vector::ZERO,
+            placement: Placement::default(),
+            scale: 1.0,
+            pivot: Vector::ZERO,
+            opacity: 1.0,
+            tint: None,
+            guides: None,
+            anchor: Anchor::default(),
+            hotspot: None,
+            touch_placement: None,
+            interactive: false,
+            hit_test: HitTest::default(),
+            replace_cursor: false,
+            interaction: None,
+            class: Theme::default(),
+            drag_status: None,
+            z_index: 0,
+            stacking: None,
+            region: None,
+            bounded: false,
+            sizing: Sizing::default(),
+            behavior: Behavior::default(),
+            callbacks: Callbacks::default(),
         }
     }
 
@@ -44,16 +128,654 @@ where
         self.offset = Vector::new(x, y);
         self
     }
+
+    /// Sets the [`Placement`] used when the content reaches the edge of the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets a fixed width for the content.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.sizing.width = Some(width.into().0);
+        self
+    }
+
+    /// Sets a fixed height for the content.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.sizing.height = Some(height.into().0);
+        self
+    }
+
+    /// Sets the maximum width of the content.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.sizing.max_width = Some(max_width.into().0);
+        self
+    }
+
+    /// Sets the maximum height of the content.
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.sizing.max_height = Some(max_height.into().0);
+        self
+    }
+
+    /// Sets the fraction of the window, or of the region when [`bounded`](Self::bounded),
+    /// the content may take on each axis. Default is `1.0`.
+    ///
+    /// [`Length::Fill`] in the content fills this space.
+    pub fn fill_fraction(mut self, fraction: f32) -> Self {
+        self.sizing.fill_fraction = fraction;
+        self
+    }
+
+    /// Sets the scale factor applied to the content when it is drawn.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the point of the content that stays in place when it is scaled,
+    /// as a fraction of its size. `(0.0, 0.0)` is the top-left corner and the default.
+    pub fn pivot(mut self, x: f32, y: f32) -> Self {
+        self.pivot = Vector::new(x, y);
+        self
+    }
+
+    /// Sets the opacity of the content, from `0.0` (invisible) to `1.0` (opaque, the default).
+    ///
+    /// Like [`Transition::fade`], this reaches what the layer draws itself and the inherited
+    /// text color, but not child widgets that set their own colors.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Covers the content with the given color, e.g. a translucent accent over a drag ghost.
+    ///
+    /// The alpha of the color sets the strength of the tint. It covers the bounds of the
+    /// content, rounded like the [`style`](Self::style) of the layer.
+    pub fn tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = Some(tint.into());
+        self
+    }
+
+    /// Draws the guide lines of a [`Crosshair`] through the content position.
+    pub fn guides(mut self, crosshair: Crosshair) -> Self {
+        self.guides = Some(crosshair);
+        self
+    }
+
+    /// Sets the [`Anchor`] of the content that is placed at the cursor plus the offset.
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.anchor = anchor.into();
+        self.hotspot = None;
+        self
+    }
+
+    /// Follows a finger on touchscreens, as well as the mouse cursor.
+    ///
+    /// The content follows the chosen [`Touch`] finger after a touch, and the mouse cursor
+    /// again once the mouse moves. It hides when the finger is lifted.
+    pub fn touch(mut self, touch: Touch) -> Self {
+        self.behavior.touch = Some(touch);
+        self
+    }
+
+    /// Sets the offset and [`Anchor`] used instead of the regular ones while following a finger.
+    pub fn touch_offset(mut self, x: f32, y: f32, anchor: impl Into<Anchor>) -> Self {
+        self.touch_placement = Some((Vector::new(x, y), anchor.into()));
+        self
+    }
+
+    /// Centers the content 48 pixels above a finger, so the finger does not cover it.
+    pub fn lift_above_finger(self) -> Self {
+        self.touch_offset(0.0, -48.0, Anchor::BOTTOM_CENTER)
+    }
+
+    /// Sets whether events are forwarded to the content, so it can react like a regular widget.
+    ///
+    /// The content only sees the cursor when it is under it; combine this with an
+    /// [`Anchor`] or a negative offset to make the content clickable. Default is `false`.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Sets whether the content blocks input to the widgets beneath it.
+    pub fn hit_test(mut self, hit_test: HitTest) -> Self {
+        self.hit_test = hit_test;
+        self
+    }
+
+    /// Sets whether the content replaces the system cursor while it is shown.
+    ///
+    /// The layer reports [`mouse::Interaction::Hidden`] wherever no other widget asks for
+    /// an interaction; lay out the rest of the application [`within`](Self::within) the
+    /// layer to hide the system cursor over all of it. Default is `false`.
+    pub fn replace_cursor(mut self, replace_cursor: bool) -> Self {
+        self.replace_cursor = replace_cursor;
+        self
+    }
+
+    /// Sets the point of the content, in pixels from its top-left corner, that is placed
+    /// at the cursor, e.g. the tip of an arrow.
+    ///
+    /// This replaces the [`Anchor`] and the offset. The hotspot scales with the content.
+    pub fn hotspot(mut self, x: f32, y: f32) -> Self {
+        self.hotspot = Some(Vector::new(x, y));
+        self.offset = Vector::ZERO;
+        self
+    }
+
+    /// Sets the [`mouse::Interaction`] shown while the content is visible, e.g.
+    /// [`mouse::Interaction::Grabbing`] during a drag.
+    ///
+    /// Like [`replace_cursor`](Self::replace_cursor), the interaction is reported by the
+    /// [`MouseLayer`] itself, so the widgets beneath keep receiving the cursor. Rows and
+    /// columns show the greatest interaction of their children, so it only overrides the
+    /// interactions of other widgets laid out [`within`](Self::within) the layer; lay out
+    /// the rest of the application within it to override the cursor over all of it.
+    pub fn interaction(self, interaction: mouse::Interaction) -> Self {
+        self.interaction_with(move |_| interaction)
+    }
+
+    /// Sets a closure computing the [`mouse::Interaction`] shown while the content is
+    /// visible from the cursor position, in window coordinates.
+    ///
+    /// Returning [`mouse::Interaction::None`] leaves the cursor to the other widgets.
+    pub fn interaction_with(mut self, interaction: impl Fn(Point) -> mouse::Interaction + 'a) -> Self {
+        self.interaction = Some(Box::new(interaction));
+        self
+    }
+
+    /// Sets the style of the [`MouseLayer`], drawn behind the content.
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MouseLayer`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the order of the layer in a [`MouseLayerStack`]; higher values are drawn on top.
+    /// Default is `0`.
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Leaves a [`Trail`] behind the content as it moves.
+    pub fn trail(mut self, trail: Trail) -> Self {
+        self.behavior.trail = Some(trail);
+        self
+    }
+
+    /// Sets how the content catches up with the cursor. Default is [`Follow::Snap`].
+    pub fn follow(mut self, follow: Follow) -> Self {
+        self.behavior.follow = follow;
+        self
+    }
+
+    /// Shows the content only while the given mouse button is held.
+    pub fn while_pressed(mut self, button: mouse::Button) -> Self {
+        self.behavior.visibility.button = Some(button);
+        self
+    }
+
+    /// Shows the content only while the cursor is over the given element.
+    ///
+    /// The element is laid out and drawn in place of the [`MouseLayer`].
+    pub fn within(mut self, region: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Keeps the content inside the element given to [`within`](Self::within) rather than
+    /// the window: the content is laid out in its size and placed within its bounds.
+    pub fn bounded(mut self, bounded: bool) -> Self {
+        self.bounded = bounded;
+        self
+    }
+
+    /// Sets how long the conditions must hold before the content is shown.
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.behavior.visibility.show_delay = delay;
+        self
+    }
+
+    /// Sets how long the conditions must fail before the content is hidden.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.behavior.visibility.hide_delay = delay;
+        self
+    }
+
+    /// Hides the content regardless of the other conditions, e.g. while nothing is dragged.
+    pub(crate) fn set_hidden(&mut self, is_hidden: bool) {
+        self.behavior.visibility.is_hidden = is_hidden;
+    }
+
+    /// Sets the place of the layer in a [`MouseLayerStack`].
+    pub(crate) fn set_stacking(&mut self, stacking: Stacking) {
+        self.stacking = Some(stacking);
+    }
+
+    /// Overrides the [`Status`] passed to the style while the content is dragged.
+    pub(crate) fn set_drag_status(&mut self, status: Option<Status>) {
+        self.drag_status = status;
+    }
+
+    /// Sets the [`Transition`] played both when the content appears and disappears.
+    pub fn transition(self, transition: Transition) -> Self {
+        self.enter(transition).exit(transition)
+    }
+
+    /// Sets the [`Transition`] played when the content appears.
+    pub fn enter(mut self, transition: Transition) -> Self {
+        self.behavior.enter = transition;
+        self
+    }
+
+    /// Sets the [`Transition`] played when the content disappears.
+    pub fn exit(mut self, transition: Transition) -> Self {
+        self.behavior.exit = transition;
+        self
+    }
+
+    /// Sets the message produced when the cursor moves inside the window.
+    ///
+    /// The closure receives the new cursor position in window coordinates and
+    /// the distance moved since the previous position.
+    pub fn on_move(mut self, on_move: impl Fn(Point, Vector) -> Message + 'a) -> Self {
+        self.callbacks.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the message produced when the mouse cursor enters the window.
+    ///
+    /// Touches starting and ending do not produce it.
+    pub fn on_enter_window(mut self, message: Message) -> Self {
+        self.callbacks.on_enter_window = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the mouse cursor leaves the window.
+    ///
+    /// Touches starting and ending do not produce it.
+    pub fn on_leave_window(mut self, message: Message) -> Self {
+        self.callbacks.on_leave_window = Some(message);
+        self
+    }
+}
+
+/// The messages a [`MouseLayer`] publishes as the cursor moves.
+struct Callbacks<'a, Message> {
+    on_move: Option<Box<dyn Fn(Point, Vector) -> Message + 'a>>,
+    on_enter_window: Option<Message>,
+    on_leave_window: Option<Message>,
+}
+
+impl<Message> Default for Callbacks<'_, Message> {
+    fn default() -> Self {
+        Self {
+            on_move: None,
+            on_enter_window: None,
+            on_leave_window: None,
+        }
+    }
+}
+
+impl<Message: Clone> Callbacks<'_, Message> {
+    /// Publishes the message matching an event and the change of the cursor position
+    /// it caused, in window coordinates.
+    ///
+    /// Only the mouse cursor enters and leaves the window; a touch that starts or ends
+    /// changes the position without publishing either message.
+    fn publish(
+        &self,
+        event: &Event,
+        from: Option<Point>,
+        to: Option<Point>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let message = match (event, from, to) {
+            (Event::Mouse(mouse::Event::CursorEntered), _, _) => self.on_enter_window.clone(),
+            (Event::Mouse(mouse::Event::CursorLeft), _, _) => self.on_leave_window.clone(),
+            (_, Some(from), Some(to)) if from != to => {
+                self.on_move.as_ref().map(|on_move| on_move(to, to - from))
+            }
+            _ => None,
+        };
+
+        if let Some(message) = message {
+            shell.publish(message);
+        }
+    }
+}
+
+/// Whether a [`MouseLayer`] blocks input to the widgets beneath its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HitTest {
+    /// The layer never captures input; widgets beneath keep receiving clicks and hover.
+    ///
+    /// Widgets inside an [`interactive`](MouseLayer::interactive) layer may still capture
+    /// the events they handle.
+    #[default]
+    PassThrough,
+    /// Clicks, scrolls and hover over the content are consumed by the layer.
+    Capture,
+    /// Behaves like [`HitTest::Capture`] when the layer is interactive,
+    /// and like [`HitTest::PassThrough`] otherwise.
+    CaptureWhenInteractive,
+}
+
+impl HitTest {
+    fn captures(self, interactive: bool) -> bool {
+        match self {
+            HitTest::PassThrough => false,
+            HitTest::Capture => true,
+            HitTest::CaptureWhenInteractive => interactive,
+        }
+    }
+}
+
+/// The point of the content that is placed at the cursor, as a fraction of its size.
+///
+/// Any `(f32, f32)` pair converts into an [`Anchor`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Anchor {
+    /// The horizontal fraction, from `0.0` (left) to `1.0` (right).
+    pub x: f32,
+    /// The vertical fraction, from `0.0` (top) to `1.0` (bottom).
+    pub y: f32,
+}
+
+impl Anchor {
+    /// The top-left corner. This is the default.
+    pub const TOP_LEFT: Self = Self::new(0.0, 0.0);
+    /// The middle of the top edge.
+    pub const TOP_CENTER: Self = Self::new(0.5, 0.0);
+    /// The top-right corner.
+    pub const TOP_RIGHT: Self = Self::new(1.0, 0.0);
+    /// The middle of the left edge.
+    pub const CENTER_LEFT: Self = Self::new(0.0, 0.5);
+    /// The center.
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    /// The middle of the right edge.
+    pub const CENTER_RIGHT: Self = Self::new(1.0, 0.5);
+    /// The bottom-left corner.
+    pub const BOTTOM_LEFT: Self = Self::new(0.0, 1.0);
+    /// The middle of the bottom edge.
+    pub const BOTTOM_CENTER: Self = Self::new(0.5, 1.0);
+    /// The bottom-right corner.
+    pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
+
+    /// Creates a new [`Anchor`] from fractions of the content size.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the distance from the top-left corner of content of the given `size` to the [`Anchor`].
+    pub fn resolve(self, size: Size) -> Vector {
+        Vector::new(size.width * self.x, size.height * self.y)
+    }
+}
+
+impl From<(f32, f32)> for Anchor {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// How a [`MouseLayer`] reacts when its content would overflow the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// The content is always placed at the cursor plus the offset, even if it gets cut off.
+    #[default]
+    Free,
+    /// The content is pushed back inside the window on every overflowing axis.
+    Clamp,
+    /// The content jumps to the opposite side of the cursor on every overflowing axis,
+    /// mirroring the offset. It is clamped if it still does not fit.
+    Flip,
+    /// The content slides horizontally along the edge and flips vertically, like a tooltip.
+    Slide,
+}
+
+impl Placement {
+    /// Computes the top-left corner of content of the given `size` following `cursor`,
+    /// kept inside `bounds` according to the [`Placement`].
+    ///
+    /// `anchor` is the distance from the top-left corner of the content to the point
+    /// placed at `cursor + offset`.
+    pub fn position(
+        self,
+        cursor: Point,
+        offset: Vector,
+        anchor: Vector,
+        size: Size,
+        bounds: Rectangle,
+    ) -> Point {
+        let x = Axis {
+            cursor: cursor.x,
+            offset: offset.x,
+            anchor: anchor.x,
+            length: size.width,
+            start: bounds.x,
+            end: bounds.x + bounds.width,
+        };
+
+        let y = Axis {
+            cursor: cursor.y,
+            offset: offset.y,
+            anchor: anchor.y,
+            length: size.height,
+            start: bounds.y,
+            end: bounds.y + bounds.height,
+        };
+
+        match self {
+            Placement::Free => Point::new(x.free(), y.free()),
+            Placement::Clamp => Point::new(x.clamp(), y.clamp()),
+            Placement::Flip => Point::new(x.flip(), y.flip()),
+            Placement::Slide => Point::new(x.clamp(), y.flip()),
+        }
+    }
+}
+
+/// A single axis of a placement problem.
+struct Axis {
+    cursor: f32,
+    offset: f32,
+    anchor: f32,
+    length: f32,
+    start: f32,
+    end: f32,
+}
+
+impl Axis {
+    fn free(&self) -> f32 {
+        self.cursor + self.offset - self.anchor
+    }
+
+    fn fits(&self, value: f32) -> bool {
+        value >= self.start && value + self.length <= self.end
+    }
+
+    fn clamp(&self) -> f32 {
+        // Content larger than the bounds sticks to the start edge.
+        self.free().min(self.end - self.length).max(self.start)
+    }
+
+    fn flip(&self) -> f32 {
+        let free = self.free();
+        let flipped = self.cursor - self.offset - (self.length - self.anchor);
+
+        if self.fits(free) {
+            free
+        } else if self.fits(flipped) {
+            flipped
+        } else {
+            self.clamp()
+        }
+    }
+}
+
+/// The size constraints of the content of a [`MouseLayer`].
+#[derive(Debug, Clone, Copy)]
+struct Sizing {
+    width: Option<f32>,
+    height: Option<f32>,
+    max_width: Option<f32>,
+    max_height: Option<f32>,
+    fill_fraction: f32,
+}
+
+impl Sizing {
+    /// Returns the [`layout::Limits`] of the content, given the space available to it.
+    fn limits(&self, available: Size) -> layout::Limits {
+        let fraction = self.fill_fraction.clamp(0.0, 1.0);
+
+        let mut limits = layout::Limits::new(Size::ZERO, available * fraction);
+
+        if let Some(max_width) = self.max_width {
+            limits = limits.max_width(max_width);
+        }
+
+        if let Some(max_height) = self.max_height {
+            limits = limits.max_height(max_height);
+        }
+
+        if let Some(width) = self.width {
+            limits = limits.width(Length::Fixed(width));
+        }
+
+        if let Some(height) = self.height {
+            limits = limits.height(Length::Fixed(height));
+        }
+
+        limits
+    }
+}
+
+impl Default for Sizing {
+    fn default() -> Self {
+        Self {
+            width: None,
+            height: None,
+            max_width: None,
+            max_height: None,
+            fill_fraction: 1.0,
+        }
+    }
+}
+
+/// The settings of a [`MouseLayer`] that drive its [`State`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Behavior {
+    follow: Follow,
+    visibility: Visibility,
+    enter: Transition,
+    exit: Transition,
+    trail: Option<Trail>,
+    touch: Option<Touch>,
 }
 
 #[derive(Default)]
 struct State {
     cursor_position: Option<Point>,
+    /// The translation of the widget in the window when the cursor last moved.
+    translation: Vector,
+    pointer: Pointer,
+    has_moved: bool,
+    is_moving: bool,
+    motion: Motion,
+    history: History,
+    toggle: Toggle,
+    appearance: Appearance,
+}
+
+impl State {
+    fn update<Message>(
+        &mut self,
+        behavior: Behavior,
+        region: Option<Rectangle>,
+        event: &Event,
+        cursor: mouse::Cursor,
+        translation: Vector,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let new_pos = self.pointer.update(behavior.touch, event, cursor, translation);
+        self.translation = translation;
+        if self.cursor_position != new_pos {
+            // Entering the window is never animated, and leaving it keeps the content
+            // where it was so it can disappear in place.
+            if new_pos.is_some() && (behavior.follow == Follow::Snap || self.cursor_position.is_none()) {
+                self.motion.jump(new_pos);
+            }
+
+            self.has_moved = self.cursor_position.is_some() && new_pos.is_some();
+
+            self.cursor_position = new_pos;
+            shell.request_redraw();
+        }
+
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => Some(*now),
+            _ => None,
+        };
+
+        if let Some(now) = now {
+            let is_catching_up = self.motion.step(behavior.follow, self.cursor_position, now);
+            let is_appearing = self.appearance.tick(now);
+
+            let is_trailing = match behavior.trail {
+                Some(trail) if self.appearance.is_shown() => {
+                    self.history.record(trail, self.motion.position(), now)
+                }
+                _ => {
+                    self.history.clear();
+                    false
+                }
+            };
+
+            // One more frame after the cursor stops lets the style settle back to idle.
+            self.is_moving = std::mem::take(&mut self.has_moved) || is_catching_up;
+
+            if self.is_moving || is_appearing || is_trailing {
+                shell.request_redraw();
+            }
+        }
+
+        if self
+            .toggle
+            .update(behavior.visibility, event, self.cursor_position, region, shell)
+        {
+            let is_visible = self.toggle.is_visible();
+            let transition = if is_visible {
+                behavior.enter
+            } else {
+                behavior.exit
+            };
+
+            self.appearance
+                .go(is_visible, transition, now.unwrap_or_else(Instant::now));
+
+            shell.request_redraw();
+        }
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for MouseLayer<'_, Message, Theme, Renderer>
 where
+    Message: Clone,
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
     fn tag(&self) -> widget::tree::Tag {
@@ -65,82 +787,214 @@ where
     }
 
     fn children(&self) -> Vec<widget::Tree> {
-        vec![widget::Tree::new(&self.content)]
+        std::iter::once(&self.content)
+            .chain(&self.region)
+            .map(widget::Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut widget::Tree) {
-        tree.diff_children(&[&self.content]);
+        match &self.region {
+            Some(region) => tree.diff_children(&[&self.content, region]),
+            None => tree.diff_children(&[&self.content]),
+        }
     }
 
     fn size(&self) -> Size<Length> {
-        Size::new(Length::Shrink, Length::Shrink) 
+        match &self.region {
+            Some(region) => region.as_widget().size(),
+            None => Size::new(Length::Shrink, Length::Shrink),
+        }
     }
 
     fn layout(
         &mut self,
-        _tree: &mut widget::Tree,
-        _renderer: &Renderer,
-        _limits: &layout::Limits,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
     ) -> layout::Node {
-        layout::Node::new(Size::ZERO)
+        match &mut self.region {
+            Some(region) => region
+                .as_widget_mut()
+                .layout(&mut tree.children[1], renderer, limits),
+            None => layout::Node::new(Size::ZERO),
+        }
     }
 
     fn draw(
         &self,
-        _tree: &widget::Tree,
-        _renderer: &mut Renderer,
-        _theme: &Theme,
-        _style: &renderer::Style,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(region) = &self.region {
+            region.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
     ) {
-       
+        if let Some(region) = &mut self.region {
+            region
+                .as_widget_mut()
+                .operate(&mut tree.children[1], layout, renderer, operation);
+        }
     }
+
     fn update(
         &mut self,
         tree: &mut widget::Tree,
-        _event: &Event,
-        _layout: Layout<'_>,
+        event: &Event,
+        layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_mut::<State>();
-        let new_pos = cursor.position();        // Option<Point>
-        if state.cursor_position != new_pos{
-            state.cursor_position = new_pos;
-            shell.request_redraw();
+        // The cursor is tracked by the overlay, which sees every event first.
+        // Here it may be unavailable while the overlay captures it.
+        if let Some(region) = &mut self.region {
+            region.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.replace_cursor
+            && state.appearance.is_shown()
+            && (self.region.is_none() || cursor.is_over(layout.bounds()))
+            && cursor.position().is_some()
+        {
+            return mouse::Interaction::Hidden;
+        }
+
+        if let Some(interaction) = &self.interaction
+            && state.toggle.is_visible()
+            && let Some(position) = cursor.position()
+        {
+            let interaction = interaction(position + state.translation);
+
+            if interaction != mouse::Interaction::None {
+                return interaction;
+            }
+        }
+
+        match &self.region {
+            Some(region) => region.as_widget().mouse_interaction(
+                &tree.children[1],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            None => mouse::Interaction::default(),
         }
     }
+
     fn overlay<'b>(
         &'b mut self,
         tree: &'b mut widget::Tree,
-        _layout: Layout<'_>,
-        _renderer: &Renderer,
+        layout: Layout<'b>,
+        renderer: &Renderer,
         viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let state = tree.state.downcast_ref::<State>();
+        let (content_tree, region_tree) = match tree.children.as_mut_slice() {
+            [content, region] => (content, Some(region)),
+            [content, ..] => (content, None),
+            [] => return None,
+        };
+
+        let region = self.region.is_some().then(|| layout.bounds());
 
-        Some(overlay::Element::new(Box::new(Overlay {
+        let region_overlay = self
+            .region
+            .as_mut()
+            .zip(region_tree)
+            .and_then(|(region, tree)| {
+                region
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            });
+
+        let layer = overlay::Element::new(Box::new(Overlay {
             content: &mut self.content,
-            tree: &mut tree.children[0],
-            cursor_position: state.cursor_position,
+            tree: content_tree,
+            state: tree.state.downcast_mut::<State>(),
+            behavior: self.behavior,
+            callbacks: &self.callbacks,
+            region,
+            bounded: self.bounded,
+            sizing: self.sizing,
             offset: self.offset,
+            stacking: self.stacking.clone(),
+            placement: self.placement,
+            scale: self.scale,
+            pivot: self.pivot,
+            opacity: self.opacity,
+            tint: self.tint,
+            guides: self.guides,
+            anchor: self.anchor,
+            hotspot: self.hotspot,
+            touch_placement: self.touch_placement,
+            interactive: self.interactive,
+            hit_test: self.hit_test,
+            replace_cursor: self.replace_cursor,
+            class: &self.class,
+            drag_status: self.drag_status,
             translation,
             viewport: *viewport,
-        })))
+        }));
+
+        Some(match region_overlay {
+            Some(region_overlay) => {
+                overlay::Group::with_children(vec![region_overlay, layer]).overlay()
+            }
+            None => layer,
+        })
     }
 }
 
 impl<'a, Message, Theme, Renderer> From<MouseLayer<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a,
-    Theme: 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
     Renderer: iced::advanced::Renderer + 'a,
 {
     fn from(layer: MouseLayer<'a, Message, Theme, Renderer>) -> Self {
@@ -152,44 +1006,193 @@ where
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
     content: &'b mut Element<'a, Message, Theme, Renderer>,
     tree: &'b mut widget::Tree,
-    cursor_position: Option<Point>,
+    state: &'b mut State,
+    behavior: Behavior,
+    callbacks: &'b Callbacks<'a, Message>,
+    region: Option<Rectangle>,
+    bounded: bool,
+    sizing: Sizing,
     offset: Vector,
+    stacking: Option<Stacking>,
+    placement: Placement,
+    scale: f32,
+    pivot: Vector,
+    opacity: f32,
+    tint: Option<Color>,
+    guides: Option<Crosshair>,
+    anchor: Anchor,
+    hotspot: Option<Vector>,
+    touch_placement: Option<(Vector, Anchor)>,
+    interactive: bool,
+    hit_test: HitTest,
+    replace_cursor: bool,
+    class: &'b Theme::Class<'a>,
+    drag_status: Option<Status>,
     translation: Vector,
     viewport: Rectangle,
 }
 
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Returns the [`Transformation`] that scales the unscaled content layout around its pivot,
+    /// including the effects of an ongoing [`Transition`].
+    fn transformation(&self, content_bounds: Rectangle) -> Transformation {
+        let pivot = content_bounds.position() + Vector::from(content_bounds.size() * self.pivot);
+        let slide = self.state.appearance.slide();
+        let scale = self.scale * self.state.appearance.scale();
+
+        Transformation::translate(pivot.x + slide.x, pivot.y + slide.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-pivot.x, -pivot.y)
+    }
+
+    /// Draws the content with its style and tint, at the given alpha.
+    fn draw_content(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        content_layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        alpha: f32,
+    ) {
+        let transformation = self.transformation(content_layout.bounds());
+
+        let status = self.drag_status.unwrap_or(if self.state.is_moving {
+            Status::Moving
+        } else {
+            Status::Idle
+        });
+
+        let appearance = theme.style(self.class, status).scale_alpha(alpha);
+
+        let style = renderer::Style {
+            text_color: style.text_color.scale_alpha(alpha),
+        };
+
+        renderer.with_transformation(transformation, |renderer| {
+            if !appearance.is_transparent() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: content_layout.bounds(),
+                        border: appearance.border,
+                        shadow: appearance.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    appearance
+                        .background
+                        .unwrap_or(Color::TRANSPARENT.into()),
+                );
+            }
+
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                &style,
+                content_layout,
+                cursor * transformation.inverse(),
+                &Rectangle::with_size(Size::INFINITE),
+            );
+
+            if let Some(tint) = self.tint {
+                // A layer of its own keeps the tint above every primitive of the content.
+                renderer.with_layer(content_layout.bounds(), |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: content_layout.bounds(),
+                            border: iced::border::rounded(appearance.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        tint.scale_alpha(alpha),
+                    );
+                });
+            }
+        });
+    }
+}
+
 impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
     for Overlay<'_, '_, Message, Theme, Renderer>
 where
+    Message: Clone,
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
-        let cursor_pos = self.cursor_position;
-        
-        let Some(cursor) = cursor_pos else {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        if let Some(stacking) = &self.stacking {
+            stacking.clear();
+        }
+
+        if !self.state.appearance.is_shown() {
             return layout::Node::new(Size::ZERO);
+        }
+
+        let Some(cursor) = self.state.motion.position() else {
+            return layout::Node::new(Size::ZERO);
+        };
+
+        // A bounded layer keeps its content inside the region, in window coordinates.
+        let bounds = match self.region {
+            Some(region) if self.bounded => region + self.translation,
+            _ => Rectangle::with_size(bounds),
         };
 
-        let limits = layout::Limits::new(Size::ZERO, self.viewport.size());
-        
+        let max = if self.bounded {
+            bounds.size()
+        } else {
+            self.viewport.size()
+        };
+
+        let limits = self.sizing.limits(max);
+
         let content_layout = self.content.as_widget_mut().layout(
             self.tree,
             renderer,
             &limits,
         );
 
-        let position = Point::new(
-            cursor.x + self.offset.x + self.translation.x,
-            cursor.y + self.offset.y + self.translation.y,
-        );
+        let size = content_layout.bounds().size();
+        let (scaled, drift) = scale_around(size, self.scale, self.pivot);
+
+        // The anchor is a point of the content as drawn, so it is found in the scaled size.
+        let (offset, anchor) = match (self.touch_placement, self.hotspot) {
+            (Some((offset, anchor)), _) if self.state.pointer.is_touch() => {
+                (offset, anchor.resolve(scaled))
+            }
+            (_, Some(hotspot)) => (self.offset, hotspot * self.scale),
+            _ => (self.offset, self.anchor.resolve(scaled)),
+        };
+
+        // Layers above the base of a stack follow the base as placed, so they never drift apart.
+        let offset = offset + self.stacking.as_ref().map_or(Vector::ZERO, Stacking::offset);
+
+        let position = match self.stacking.as_ref().and_then(Stacking::base) {
+            Some(base) => Placement::Free.position(base, offset, anchor, scaled, bounds),
+            None => self.placement.position(
+                cursor + self.translation,
+                offset,
+                anchor,
+                scaled,
+                bounds,
+            ),
+        };
+
+        if let Some(stacking) = &self.stacking {
+            stacking.place(position);
+        }
 
         layout::Node::with_children(
-            content_layout.bounds().size(),
-            vec![content_layout],
+            scaled,
+            vec![content_layout.move_to(Point::ORIGIN + drift)],
         )
         .move_to(position)
     }
@@ -202,7 +1205,11 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
     ) {
-        if self.cursor_position.is_none() {
+        let Some(position) = self.state.motion.position() else {
+            return;
+        };
+
+        if !self.state.appearance.is_shown() {
             return;
         }
 
@@ -210,40 +1217,222 @@ where
             return;
         };
 
-        self.content.as_widget().draw(
-            self.tree,
-            renderer,
-            theme,
-            style,
-            content_layout,
-            cursor,
-            &Rectangle::with_size(Size::INFINITE),
-        );
+        let alpha = self.opacity * self.state.appearance.alpha();
+
+        if let Some(guides) = self.guides {
+            let bounds = self.region.unwrap_or(self.viewport);
+
+            guides.draw(
+                renderer,
+                position + self.translation,
+                bounds + self.translation,
+                alpha,
+            );
+        }
+
+        match self.behavior.trail {
+            Some(trail @ Trail { kind: trail::Kind::Line { width, color }, .. }) => {
+                let samples: Vec<_> = self.state.history.samples(trail).collect();
+
+                for pair in samples.windows(2) {
+                    let [(from, _), (to, strength)] = pair else {
+                        continue;
+                    };
+
+                    draw_segment(
+                        renderer,
+                        *from + self.translation,
+                        *to + self.translation,
+                        width,
+                        color.scale_alpha(strength * alpha),
+                    );
+                }
+            }
+            Some(trail @ Trail { kind: trail::Kind::Copies, .. }) => {
+                for (sample, strength) in self.state.history.samples(trail) {
+                    if sample != position {
+                        // Primitives of a single layer are batched by kind, so every copy
+                        // gets a layer of its own to stay beneath the newer ones.
+                        renderer.with_layer(Rectangle::INFINITE, |renderer| {
+                            renderer.with_translation(sample - position, |renderer| {
+                                self.draw_content(
+                                    renderer,
+                                    theme,
+                                    style,
+                                    content_layout,
+                                    mouse::Cursor::Unavailable,
+                                    strength * alpha,
+                                );
+                            });
+                        });
+                    }
+                }
+            }
+            None => {}
+        }
+
+        renderer.with_layer(Rectangle::INFINITE, |renderer| {
+            self.draw_content(renderer, theme, style, content_layout, cursor, alpha);
+        });
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if !self.interactive {
+            return;
+        }
+
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+
+        self.content
+            .as_widget_mut()
+            .operate(self.tree, content_layout, renderer, operation);
     }
 
     fn update(
         &mut self,
-        _event: &Event,
-        _layout: Layout<'_>,
+        event: &Event,
+        layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) {
-        let new_pos = cursor.position();
-        if self.cursor_position != new_pos {
-            self.cursor_position = new_pos;
-            shell.request_redraw();
+        let previous = self.state.cursor_position;
+
+        // The state is kept in the coordinates of the widget, like the region.
+        self.state.update(
+            self.behavior,
+            self.region,
+            event,
+            cursor,
+            self.translation,
+            shell,
+        );
+
+        self.callbacks.publish(
+            event,
+            previous.map(|position| position + self.translation),
+            self.state.cursor_position.map(|position| position + self.translation),
+            shell,
+        );
+
+        if self.interactive
+            && let Some(content_layout) = layout.children().next()
+        {
+            let transformation = self.transformation(content_layout.bounds());
+
+            self.content.as_widget_mut().update(
+                self.tree,
+                event,
+                content_layout,
+                cursor * transformation.inverse(),
+                renderer,
+                clipboard,
+                shell,
+                &Rectangle::with_size(Size::INFINITE),
+            );
+        }
+
+        let is_click = matches!(
+            event,
+            Event::Mouse(
+                mouse::Event::ButtonPressed(_)
+                    | mouse::Event::ButtonReleased(_)
+                    | mouse::Event::WheelScrolled { .. }
+            ) | Event::Touch(_)
+        );
+
+        if is_click
+            && self.hit_test.captures(self.interactive)
+            && cursor.is_over(layout.bounds())
+        {
+            shell.capture_event();
         }
     }
 
     fn mouse_interaction(
         &self,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse::Interaction::default()
+        let interaction = match layout.children().next() {
+            Some(content_layout) if self.interactive => {
+                let transformation = self.transformation(content_layout.bounds());
+
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content_layout,
+                    cursor * transformation.inverse(),
+                    &Rectangle::with_size(Size::INFINITE),
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        };
+
+        // Any interaction other than `None` hides the cursor from the widgets beneath.
+        let interaction = if interaction == mouse::Interaction::None
+            && self.hit_test.captures(self.interactive)
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Idle
+        } else {
+            interaction
+        };
+
+        // The content is the cursor; the widgets beneath report `Hidden` themselves.
+        if self.replace_cursor && interaction != mouse::Interaction::None {
+            mouse::Interaction::Hidden
+        } else {
+            interaction
+        }
+    }
+}
+
+/// Returns the size of content of the given `size` scaled around `pivot`, and the
+/// offset of its unscaled layout from the top-left corner of the scaled content.
+fn scale_around(size: Size, scale: f32, pivot: Vector) -> (Size, Vector) {
+    // The pivot keeps its place, so the unscaled layout drifts from the scaled content by the difference.
+    let pivot = Vector::from(size * pivot);
+
+    (size * scale, pivot * scale - pivot)
+}
+
+/// Draws a line of the given width from `from` to `to` as a row of overlapping dots,
+/// since the renderer has no paths.
+fn draw_segment<Renderer>(renderer: &mut Renderer, from: Point, to: Point, width: f32, color: Color)
+where
+    Renderer: iced::advanced::Renderer,
+{
+    let width = width.max(1.0);
+    let length = from.distance(to);
+    let steps = ((length / (width / 2.0)).ceil() as usize).clamp(1, 256);
+
+    for step in 1..=steps {
+        let center = from + (to - from) * (step as f32 / steps as f32);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    center - Vector::new(width / 2.0, width / 2.0),
+                    Size::new(width, width),
+                ),
+                border: iced::Border {
+                    radius: (width / 2.0).into(),
+                    ..iced::Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
     }
 }
 
@@ -252,7 +1441,130 @@ pub fn mouse_layer<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
 ) -> MouseLayer<'a, Message, Theme, Renderer>
 where
+    Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
     MouseLayer::new(content)
-}
\ No newline at end of file
+}
+
+/// Creates a new [`MouseLayer`] drawing `content` in place and showing `follower` only
+/// while the cursor is over it, clamped to its bounds.
+pub fn bounded_mouse_layer<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    follower: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> MouseLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    MouseLayer::new(follower)
+        .within(content)
+        .bounded(true)
+        .placement(Placement::Clamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    const SIZE: Size = Size::new(100.0, 50.0);
+
+    fn position(placement: Placement, cursor: Point) -> Point {
+        placement.position(cursor, Vector::new(15.0, 15.0), Vector::ZERO, SIZE, WINDOW)
+    }
+
+    #[test]
+    fn free_ignores_the_window() {
+        assert_eq!(
+            position(Placement::Free, Point::new(790.0, 590.0)),
+            Point::new(805.0, 605.0)
+        );
+    }
+
+    #[test]
+    fn clamp_pushes_back_inside() {
+        assert_eq!(
+            position(Placement::Clamp, Point::new(790.0, 590.0)),
+            Point::new(700.0, 550.0)
+        );
+    }
+
+    #[test]
+    fn flip_mirrors_the_offset_at_the_bottom_right_corner() {
+        let cursor = Point::new(790.0, 590.0);
+
+        assert_eq!(
+            position(Placement::Flip, cursor),
+            cursor - Vector::new(SIZE.width + 15.0, SIZE.height + 15.0)
+        );
+    }
+
+    #[test]
+    fn flip_keeps_axes_that_fit() {
+        assert_eq!(
+            position(Placement::Flip, Point::new(790.0, 100.0)),
+            Point::new(675.0, 115.0)
+        );
+    }
+
+    #[test]
+    fn flip_clamps_when_neither_side_fits() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(120.0, 600.0));
+
+        let position = Placement::Flip.position(
+            Point::new(60.0, 100.0),
+            Vector::new(15.0, 15.0),
+            Vector::ZERO,
+            SIZE,
+            bounds,
+        );
+
+        assert_eq!(position, Point::new(20.0, 115.0));
+    }
+
+    #[test]
+    fn slide_clamps_horizontally_and_flips_vertically() {
+        assert_eq!(
+            position(Placement::Slide, Point::new(790.0, 590.0)),
+            Point::new(700.0, 525.0)
+        );
+    }
+
+    #[test]
+    fn anchor_of_scaled_content_is_placed_at_the_cursor() {
+        let cursor = Point::new(400.0, 300.0);
+
+        for pivot in [Vector::new(0.0, 0.0), Vector::new(1.0, 1.0)] {
+            let (scaled, drift) = scale_around(SIZE, 0.5, pivot);
+
+            let position =
+                Placement::Free.position(cursor, Vector::ZERO, Anchor::CENTER.resolve(scaled), scaled, WINDOW);
+
+            // The content is laid out at `drift` and drawn scaled around its pivot.
+            let layout = Rectangle::new(position + drift, SIZE);
+            let pivot = layout.position() + Vector::from(SIZE * pivot);
+            let transformation = Transformation::translate(pivot.x, pivot.y)
+                * Transformation::scale(0.5)
+                * Transformation::translate(-pivot.x, -pivot.y);
+
+            assert_eq!(layout.center() * transformation, cursor);
+        }
+    }
+
+    #[test]
+    fn anchor_is_placed_at_the_cursor() {
+        let anchor = Anchor::CENTER.resolve(SIZE);
+
+        let position =
+            Placement::Free.position(Point::new(400.0, 300.0), Vector::ZERO, anchor, SIZE, WINDOW);
+
+        assert_eq!(position, Point::new(350.0, 275.0));
+    }
+}
@@ -0,0 +1,430 @@
+//! A lens showing a zoomed view of the content under the cursor.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::{Color, Element, Event, Length, Point, Rectangle, Size, Transformation, Vector};
+
+use crate::{Anchor, Catalog, Placement, Status, Style, StyleFn};
+
+/// An element that shows a zoomed view of itself in a lens following the cursor.
+///
+/// The content is drawn a second time inside the lens, scaled around the cursor and
+/// clipped to the lens; nothing is captured from the screen.
+pub struct Magnifier<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    zoom: f32,
+    size: Size,
+    lens: Lens,
+    offset: Vector,
+    anchor: Anchor,
+    placement: Placement,
+    class: Theme::Class<'a>,
+}
+
+/// The shape of the lens of a [`Magnifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lens {
+    /// A rectangular lens. This is the default.
+    #[default]
+    Rectangle,
+    /// A circular lens.
+    ///
+    /// Renderers only clip to rectangles, so the zoomed view fills the largest square
+    /// inside the circle, framed by the style of the [`Magnifier`].
+    Circle,
+}
+
+impl<'a, Message, Theme, Renderer> Magnifier<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`Magnifier`] of the given content.
+    ///
+    /// By default the lens is a 120×120 rectangle centered on the cursor, zooming 2×.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            zoom: 2.0,
+            size: Size::new(120.0, 120.0),
+            lens: Lens::default(),
+            offset: Vector::ZERO,
+            anchor: Anchor::CENTER,
+            placement: Placement::Free,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the zoom factor of the lens.
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the size of the lens.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Size::new(width, height);
+        self
+    }
+
+    /// Sets the [`Lens`] shape.
+    pub fn lens(mut self, lens: Lens) -> Self {
+        self.lens = lens;
+        self
+    }
+
+    /// Sets the offset of the lens from the cursor position.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Vector::new(x, y);
+        self
+    }
+
+    /// Sets the [`Anchor`] of the lens that is placed at the cursor plus the offset.
+    /// Default is [`Anchor::CENTER`].
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Sets the [`Placement`] used when the lens reaches the edge of the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the style of the lens, e.g. [`elevated`](crate::elevated) for a framed lens.
+    ///
+    /// The background fills the lens behind the zoomed view.
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the lens.
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    cursor_position: Option<Point>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Magnifier<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let [content_tree] = tree.children.as_mut_slice() else {
+            return None;
+        };
+
+        // The overlays of the content are nested in the lens, which needs the content too.
+        Some(overlay::Element::new(Box::new(Overlay {
+            content: &mut self.content,
+            tree: content_tree,
+            content_layout: layout,
+            state: tree.state.downcast_mut::<State>(),
+            zoom: self.zoom,
+            size: self.size,
+            lens: self.lens,
+            offset: self.offset,
+            anchor: self.anchor,
+            placement: self.placement,
+            class: &self.class,
+            viewport: *viewport,
+            translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Magnifier<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(magnifier: Magnifier<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(magnifier)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut widget::Tree,
+    content_layout: Layout<'b>,
+    state: &'b mut State,
+    zoom: f32,
+    size: Size,
+    lens: Lens,
+    offset: Vector,
+    anchor: Anchor,
+    placement: Placement,
+    class: &'b Theme::Class<'a>,
+    viewport: Rectangle,
+    translation: Vector,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let Some(cursor) = self.state.cursor_position else {
+            return layout::Node::new(Size::ZERO);
+        };
+
+        let position = self.placement.position(
+            cursor + self.translation,
+            self.offset,
+            self.anchor.resolve(self.size),
+            self.size,
+            Rectangle::with_size(bounds),
+        );
+
+        layout::Node::new(self.size).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let Some(cursor) = self.state.cursor_position else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class, Status::Idle);
+
+        let radius = match self.lens {
+            Lens::Rectangle => appearance.border.radius,
+            Lens::Circle => (bounds.width.min(bounds.height) / 2.0).into(),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: iced::Border {
+                    radius,
+                    ..appearance.border
+                },
+                shadow: appearance.shadow,
+                ..renderer::Quad::default()
+            },
+            appearance.background.unwrap_or(Color::TRANSPARENT.into()),
+        );
+
+        let view = match self.lens {
+            Lens::Rectangle => bounds.shrink(appearance.border.width),
+            Lens::Circle => {
+                let side = (bounds.width.min(bounds.height) - 2.0 * appearance.border.width).max(0.0)
+                    / std::f32::consts::SQRT_2;
+
+                Rectangle::new(
+                    bounds.center() - Vector::new(side / 2.0, side / 2.0),
+                    Size::new(side, side),
+                )
+            }
+        };
+
+        // The content lives in the coordinates of the widget; the point under the cursor
+        // is moved to the center of the lens.
+        let center = view.center();
+        let transformation = Transformation::translate(center.x, center.y)
+            * Transformation::scale(self.zoom)
+            * Transformation::translate(-cursor.x, -cursor.y);
+
+        renderer.with_layer(view, |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    self.content_layout,
+                    mouse::Cursor::Unavailable,
+                    &Rectangle::with_size(Size::INFINITE),
+                );
+            });
+        });
+    }
+
+    fn update(
+        &mut self,
+        _event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        // The overlay sees every event first, even when other overlays hide the cursor
+        // from the widgets beneath.
+        let position = (cursor - self.translation).position_over(self.content_layout.bounds());
+
+        if self.state.cursor_position != position {
+            self.state.cursor_position = position;
+            shell.request_redraw();
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        _layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            self.content_layout,
+            renderer,
+            &self.viewport,
+            self.translation,
+        )
+    }
+}
+
+/// Creates a new [`Magnifier`] of the given content.
+pub fn magnifier<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Magnifier<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Magnifier::new(content)
+}
@@ -0,0 +1,480 @@
+//! The cursors of other users, positioned by the application.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::time::{Duration, Instant};
+use iced::window;
+use iced::{Element, Event, Length, Point, Rectangle, Size, Vector};
+
+use crate::follow::Motion;
+use crate::transition::Appearance;
+use crate::{Anchor, Follow, Transition};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// An element that draws virtual cursors over its content, e.g. the cursors of the
+/// other users of a collaborative editor.
+///
+/// Every cursor is an element drawn at a position relative to the top-left corner of
+/// the content. Cursors glide between the positions they are given, and fade out once
+/// their position has not changed for a while.
+pub struct CursorPresence<'a, Id, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    cursors: Vec<Entry<'a, Id, Message, Theme, Renderer>>,
+    offset: Vector,
+    anchor: Anchor,
+    follow: Follow,
+    stale_after: Duration,
+    transition: Transition,
+}
+
+struct Entry<'a, Id, Message, Theme, Renderer> {
+    id: Id,
+    position: Point,
+    element: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Id, Message, Theme, Renderer> CursorPresence<'a, Id, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`CursorPresence`] drawing cursors over the given content.
+    ///
+    /// By default cursors glide with a [`Follow::Spring`] of 60 milliseconds, and fade
+    /// out over 300 milliseconds after 5 seconds without moving.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            cursors: Vec::new(),
+            offset: Vector::ZERO,
+            anchor: Anchor::default(),
+            follow: Follow::Spring(Duration::from_millis(60)),
+            stale_after: Duration::from_secs(5),
+            transition: Transition::new(Duration::from_millis(300)).fade(),
+        }
+    }
+
+    /// Adds the cursor identified by `id`, drawing `element` at `position`.
+    ///
+    /// The `id` ties the cursor to its animation across views; it must be unique.
+    pub fn push(
+        mut self,
+        id: Id,
+        position: Point,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.cursors.push(Entry {
+            id,
+            position,
+            element: element.into(),
+        });
+        self
+    }
+
+    /// Adds every cursor of the given `(id, position, element)` entries.
+    pub fn extend(
+        self,
+        cursors: impl IntoIterator<Item = (Id, Point, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        cursors
+            .into_iter()
+            .fold(self, |presence, (id, position, element)| presence.push(id, position, element))
+    }
+
+    /// Sets the offset of every cursor element from its position.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Vector::new(x, y);
+        self
+    }
+
+    /// Sets the [`Anchor`] of every cursor element that is placed at its position plus the offset.
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Sets how cursors glide to their new positions.
+    pub fn follow(mut self, follow: Follow) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Sets how long a cursor may keep its position before it fades out.
+    pub fn stale_after(mut self, duration: Duration) -> Self {
+        self.stale_after = duration;
+        self
+    }
+
+    /// Sets the [`Transition`] played when a cursor appears, goes stale or moves again.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+/// The animation of a cursor, kept across views by its id.
+struct Track {
+    goal: Point,
+    motion: Motion,
+    appearance: Appearance,
+    updated_at: Instant,
+    is_stale: bool,
+}
+
+struct State<Id> {
+    tracks: HashMap<Id, Track>,
+}
+
+impl<Id> State<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    /// Starts tracking new cursors, forgets removed ones and revives the ones that moved.
+    fn sync<Message, Theme, Renderer>(
+        &mut self,
+        cursors: &[Entry<'_, Id, Message, Theme, Renderer>],
+        transition: Transition,
+        now: Instant,
+    ) {
+        self.tracks
+            .retain(|id, _| cursors.iter().any(|cursor| cursor.id == *id));
+
+        for cursor in cursors {
+            let track = self.tracks.entry(cursor.id.clone()).or_insert_with(|| {
+                let mut motion = Motion::default();
+                motion.jump(Some(cursor.position));
+
+                let mut appearance = Appearance::default();
+                appearance.go(true, transition, now);
+
+                Track {
+                    goal: cursor.position,
+                    motion,
+                    appearance,
+                    updated_at: now,
+                    is_stale: false,
+                }
+            });
+
+            if track.goal != cursor.position {
+                track.goal = cursor.position;
+                track.updated_at = now;
+
+                if track.is_stale {
+                    track.is_stale = false;
+                    track.appearance.go(true, transition, now);
+                }
+            }
+        }
+    }
+}
+
+impl<Id, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CursorPresence<'_, Id, Message, Theme, Renderer>
+where
+    Id: Eq + Hash + Clone + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        let mut state = State {
+            tracks: HashMap::new(),
+        };
+
+        state.sync(&self.cursors, self.transition, Instant::now());
+
+        widget::tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        std::iter::once(&self.content)
+            .chain(self.cursors.iter().map(|cursor| &cursor.element))
+            .map(widget::Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(self.cursors.iter().map(|cursor| &cursor.element))
+            .collect();
+
+        tree.diff_children(&children);
+
+        tree.state
+            .downcast_mut::<State<Id>>()
+            .sync(&self.cursors, self.transition, Instant::now());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<Id>>();
+        let mut is_animating = false;
+
+        for track in state.tracks.values_mut() {
+            is_animating |= track.motion.step(self.follow, Some(track.goal), *now);
+
+            if !track.is_stale {
+                let stale_at = track.updated_at + self.stale_after;
+
+                if *now >= stale_at {
+                    track.is_stale = true;
+                    track.appearance.go(false, self.transition, *now);
+                } else {
+                    shell.request_redraw_at(stale_at);
+                }
+            }
+
+            is_animating |= track.appearance.tick(*now);
+        }
+
+        if is_animating {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let [content_tree, cursor_trees @ ..] = tree.children.as_mut_slice() else {
+            return None;
+        };
+
+        let content_overlay = self.content.as_widget_mut().overlay(
+            content_tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let cursors = overlay::Element::new(Box::new(Overlay {
+            cursors: &mut self.cursors,
+            trees: cursor_trees,
+            tracks: &tree.state.downcast_ref::<State<Id>>().tracks,
+            origin: layout.position() + translation,
+            offset: self.offset,
+            anchor: self.anchor,
+        }));
+
+        Some(match content_overlay {
+            Some(content_overlay) => {
+                overlay::Group::with_children(vec![content_overlay, cursors]).overlay()
+            }
+            None => cursors,
+        })
+    }
+}
+
+impl<'a, Id, Message, Theme, Renderer> From<CursorPresence<'a, Id, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Id: Eq + Hash + Clone + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(presence: CursorPresence<'a, Id, Message, Theme, Renderer>) -> Self {
+        Element::new(presence)
+    }
+}
+
+struct Overlay<'a, 'b, Id, Message, Theme, Renderer> {
+    cursors: &'b mut [Entry<'a, Id, Message, Theme, Renderer>],
+    trees: &'b mut [widget::Tree],
+    tracks: &'b HashMap<Id, Track>,
+    origin: Point,
+    offset: Vector,
+    anchor: Anchor,
+}
+
+impl<Id, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Id, Message, Theme, Renderer>
+where
+    Id: Eq + Hash,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let children = self
+            .cursors
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .map(|(cursor, tree)| {
+                let position = self
+                    .tracks
+                    .get(&cursor.id)
+                    .filter(|track| track.appearance.is_shown())
+                    .and_then(|track| track.motion.position());
+
+                let Some(position) = position else {
+                    return layout::Node::new(Size::ZERO);
+                };
+
+                let node = cursor.element.as_widget_mut().layout(tree, renderer, &limits);
+                let anchor = self.anchor.resolve(node.size());
+
+                node.move_to(
+                    self.origin + (position - Point::ORIGIN) + self.offset - anchor,
+                )
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, children)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        for ((cursor, tree), layout) in self.cursors.iter().zip(self.trees.iter()).zip(layout.children()) {
+            let Some(track) = self
+                .tracks
+                .get(&cursor.id)
+                .filter(|track| track.appearance.is_shown())
+            else {
+                continue;
+            };
+
+            let style = renderer::Style {
+                text_color: style.text_color.scale_alpha(track.appearance.alpha()),
+            };
+
+            let bounds = layout.bounds();
+            let pivot = bounds.position() + self.anchor.resolve(bounds.size());
+
+            renderer.with_transformation(track.appearance.transformation(pivot), |renderer| {
+                // Virtual cursors are not interactive, so they never see the real one.
+                cursor.element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &Rectangle::with_size(Size::INFINITE),
+                );
+            });
+        }
+    }
+}
+
+/// Creates a new [`CursorPresence`] drawing the given `(id, position, element)` cursors
+/// over `content`.
+pub fn cursor_presence<'a, Id, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    cursors: impl IntoIterator<Item = (Id, Point, Element<'a, Message, Theme, Renderer>)>,
+) -> CursorPresence<'a, Id, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    CursorPresence::new(content).extend(cursors)
+}
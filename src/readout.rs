@@ -0,0 +1,279 @@
+//! A label showing the cursor position.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::widget::text;
+use iced::{Element, Event, Length, Pixels, Point, Rectangle, Size, Vector};
+
+use crate::{Anchor, Catalog, Follow, MouseLayer, Placement, State, Status, Style, StyleFn};
+
+/// A [`MouseLayer`] showing the cursor position, transformed and formatted by the application.
+///
+/// The label is only formatted again when the cursor moves.
+pub struct CursorReadout<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    layer: MouseLayer<'a, Message, Theme, Renderer>,
+    transform: Box<dyn Fn(Point) -> Point + 'a>,
+    format: Box<dyn Fn(Point) -> String + 'a>,
+    size: Option<Pixels>,
+}
+
+impl<'a, Message, Theme, Renderer> CursorReadout<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + text::Catalog + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    /// Creates a new [`CursorReadout`] formatting the cursor position with the given closure.
+    ///
+    /// By default the label sits at `(12, 12)` from the cursor and flips at the edges of the window.
+    pub fn new(format: impl Fn(Point) -> String + 'a) -> Self {
+        Self {
+            layer: MouseLayer::new(text(""))
+                .offset(12.0, 12.0)
+                .placement(Placement::Flip),
+            transform: Box::new(|position| position),
+            format: Box::new(format),
+            size: None,
+        }
+    }
+
+    /// Sets the closure mapping the cursor position before it is formatted, e.g. from
+    /// pixels to the data space of a plot.
+    ///
+    /// The position is in window coordinates, or relative to the top-left corner of the
+    /// region when the readout is shown [`within`](Self::within) an element.
+    pub fn transform(mut self, transform: impl Fn(Point) -> Point + 'a) -> Self {
+        self.transform = Box::new(transform);
+        self
+    }
+
+    /// Shows the readout only while the cursor is over the given element, measuring
+    /// the position from its top-left corner.
+    pub fn within(mut self, region: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.layer = self.layer.within(region);
+        self
+    }
+
+    /// Sets the text size of the label.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the offset of the label from the cursor position.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.layer = self.layer.offset(x, y);
+        self
+    }
+
+    /// Sets the [`Placement`] used when the label reaches the edge of the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.layer = self.layer.placement(placement);
+        self
+    }
+
+    /// Sets the [`Anchor`] of the label that is placed at the cursor plus the offset.
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.layer = self.layer.anchor(anchor);
+        self
+    }
+
+    /// Sets how the label catches up with the cursor.
+    pub fn follow(mut self, follow: Follow) -> Self {
+        self.layer = self.layer.follow(follow);
+        self
+    }
+
+    /// Sets the style drawn behind the label.
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.layer = self.layer.style(style);
+        self
+    }
+}
+
+/// The last formatted position.
+#[derive(Debug, Default)]
+struct Readout {
+    position: Option<Point>,
+    label: String,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CursorReadout<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog + text::Catalog + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Readout>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Readout::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.layer as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.layer as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.layer.size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.layer.layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.layer.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.layer
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.layer.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.layer
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let [layer_tree] = tree.children.as_mut_slice() else {
+            return None;
+        };
+
+        // The layer tracks the cursor in the coordinates of the widget.
+        let position = layer_tree.state.downcast_ref::<State>().cursor_position;
+        let readout = tree.state.downcast_mut::<Readout>();
+
+        if readout.position != position {
+            readout.position = position;
+
+            if let Some(position) = position {
+                let position = if self.layer.region.is_some() {
+                    position - (layout.position() - Point::ORIGIN)
+                } else {
+                    position + translation
+                };
+
+                readout.label = (self.format)((self.transform)(position));
+            }
+        }
+
+        let mut label = text(readout.label.clone());
+
+        if let Some(size) = self.size {
+            label = label.size(size);
+        }
+
+        let content = Element::from(label);
+        layer_tree.children[0].diff(&content);
+        self.layer.content = content;
+
+        self.layer
+            .overlay(layer_tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CursorReadout<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + text::Catalog + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    fn from(readout: CursorReadout<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(readout)
+    }
+}
+
+/// Creates a new [`CursorReadout`] formatting the cursor position with the given closure.
+pub fn cursor_readout<'a, Message, Theme, Renderer>(
+    format: impl Fn(Point) -> String + 'a,
+) -> CursorReadout<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + text::Catalog + 'a,
+    Renderer: iced::advanced::text::Renderer + 'a,
+{
+    CursorReadout::new(format)
+}
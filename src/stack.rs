@@ -0,0 +1,410 @@
+//! Several mouse layers sharing one overlay.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::{Element, Event, Length, Point, Rectangle, Size, Vector};
+
+use crate::{Catalog, MouseLayer};
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A group of [`MouseLayer`]s following the cursor together, drawn in the order of
+/// their [`z_index`](MouseLayer::z_index).
+///
+/// The bottom layer is placed like a standalone [`MouseLayer`]. Every layer above it is
+/// placed relative to the top-left corner of the bottom layer as placed, plus the
+/// stacking offset times its rank, so the layers stay together when the bottom one
+/// flips or is clamped at the edges of the window.
+pub struct MouseLayerStack<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    layers: Vec<MouseLayer<'a, Message, Theme, Renderer>>,
+    stack_offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> MouseLayerStack<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates an empty [`MouseLayerStack`].
+    pub fn new() -> Self {
+        Self::with_layers(Vec::new())
+    }
+
+    /// Creates a [`MouseLayerStack`] with the given layers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a layer is shown [`within`](MouseLayer::within) an element; stacked layers
+    /// take no space in the layout.
+    pub fn with_layers(layers: impl IntoIterator<Item = MouseLayer<'a, Message, Theme, Renderer>>) -> Self {
+        layers.into_iter().fold(
+            Self {
+                layers: Vec::new(),
+                stack_offset: Vector::ZERO,
+            },
+            Self::push,
+        )
+    }
+
+    /// Adds a layer to the [`MouseLayerStack`].
+    ///
+    /// Layers with the same z-index are drawn in the order they were pushed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layer is shown [`within`](MouseLayer::within) an element; stacked layers
+    /// take no space in the layout.
+    pub fn push(mut self, layer: MouseLayer<'a, Message, Theme, Renderer>) -> Self {
+        assert!(
+            layer.region.is_none(),
+            "a layer of a MouseLayerStack cannot be shown within an element"
+        );
+
+        self.layers.push(layer);
+        self
+    }
+
+    /// Sets the offset added to each layer per rank, from the bottom of the stack,
+    /// e.g. `(4.0, 4.0)` to fan out a pile of cards. Default is `(0.0, 0.0)`.
+    pub fn stack_offset(mut self, x: f32, y: f32) -> Self {
+        self.stack_offset = Vector::new(x, y);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Default for MouseLayerStack<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MouseLayerStack<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        self.layers
+            .iter()
+            .map(|layer| widget::Tree::new(layer as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let layers: Vec<_> = self
+            .layers
+            .iter()
+            .map(|layer| layer as &dyn Widget<_, _, _>)
+            .collect();
+
+        tree.diff_children(&layers);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::with_children(
+            Size::ZERO,
+            self.layers
+                .iter_mut()
+                .zip(&mut tree.children)
+                .map(|(layer, tree)| layer.layout(tree, renderer, limits))
+                .collect(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((layer, tree), layout) in self.layers.iter().zip(&tree.children).zip(layout.children()) {
+            layer.draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        for ((layer, tree), layout) in self
+            .layers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            layer.operate(tree, layout, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((layer, tree), layout) in self
+            .layers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            layer.update(tree, event, layout, cursor, renderer, clipboard, shell, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.layers
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((layer, tree), layout)| {
+                layer.mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut order: Vec<usize> = (0..self.layers.len()).collect();
+        order.sort_by_key(|&index| self.layers[index].z_index);
+
+        let base = Rc::new(Cell::new(None));
+
+        for (rank, &index) in order.iter().enumerate() {
+            self.layers[index].set_stacking(Stacking {
+                base: Rc::clone(&base),
+                rank,
+                offset: self.stack_offset * rank as f32,
+            });
+        }
+
+        let mut layers: Vec<_> = self
+            .layers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((layer, tree), layout)| {
+                let z_index = layer.z_index;
+
+                (z_index, layer.overlay(tree, layout, renderer, viewport, translation))
+            })
+            .collect();
+
+        layers.sort_by_key(|(z_index, _)| *z_index);
+
+        let children: Vec<_> = layers.into_iter().filter_map(|(_, layer)| layer).collect();
+
+        (!children.is_empty()).then(|| overlay::Element::new(Box::new(Overlay { children })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MouseLayerStack<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(stack: MouseLayerStack<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(stack)
+    }
+}
+
+/// The place of a [`MouseLayer`] in a [`MouseLayerStack`].
+#[derive(Debug, Clone)]
+pub(crate) struct Stacking {
+    /// The top-left corner of the bottom layer in the current layout, shared by every layer.
+    base: Rc<Cell<Option<Point>>>,
+    rank: usize,
+    offset: Vector,
+}
+
+impl Stacking {
+    /// Forgets where the bottom layer was placed, before it is laid out again.
+    pub fn clear(&self) {
+        if self.rank == 0 {
+            self.base.set(None);
+        }
+    }
+
+    /// Records where the bottom layer is placed.
+    pub fn place(&self, position: Point) {
+        if self.rank == 0 {
+            self.base.set(Some(position));
+        }
+    }
+
+    /// Returns the top-left corner of the bottom layer, for the layers above it.
+    ///
+    /// Layers above a hidden bottom layer follow the cursor on their own.
+    pub fn base(&self) -> Option<Point> {
+        if self.rank == 0 {
+            None
+        } else {
+            self.base.get()
+        }
+    }
+
+    /// Returns the stacking offset of the layer.
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+}
+
+/// The overlays of the layers of a [`MouseLayerStack`], from bottom to top.
+struct Overlay<'a, Message, Theme, Renderer> {
+    children: Vec<overlay::Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        layout::Node::with_children(
+            bounds,
+            self.children
+                .iter_mut()
+                .map(|child| child.as_overlay_mut().layout(renderer, bounds))
+                .collect(),
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        // Primitives of a single layer are batched by kind, so only separate layers
+        // guarantee that every layer is drawn entirely above the ones beneath it.
+        for (child, child_layout) in self.children.iter().zip(layout.children()) {
+            renderer.with_layer(layout.bounds(), |renderer| {
+                child
+                    .as_overlay()
+                    .draw(renderer, theme, style, child_layout, cursor);
+            });
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            child.as_overlay_mut().operate(layout, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        // The topmost layer sees events first, so it can capture them from the ones beneath.
+        for (child, layout) in self.children.iter_mut().zip(layout.children()).rev() {
+            child
+                .as_overlay_mut()
+                .update(event, layout, cursor, renderer, clipboard, shell);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .rev()
+            .map(|(child, layout)| child.as_overlay().mouse_interaction(layout, cursor, renderer))
+            .find(|interaction| *interaction != mouse::Interaction::None)
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.as_overlay_mut().overlay(layout, renderer))
+            .collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// Creates a new [`MouseLayerStack`] with the given layers.
+pub fn mouse_layer_stack<'a, Message, Theme, Renderer>(
+    layers: impl IntoIterator<Item = MouseLayer<'a, Message, Theme, Renderer>>,
+) -> MouseLayerStack<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    MouseLayerStack::with_layers(layers)
+}
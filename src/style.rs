@@ -0,0 +1,113 @@
+//! The appearance of the floating layer.
+use iced::{Background, Border, Color, Shadow, Theme, Vector};
+
+/// The status of a [`MouseLayer`](crate::MouseLayer), passed to its style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The content rests at the cursor.
+    Idle,
+    /// The cursor moved since the last frame, or the content is still catching up with it.
+    Moving,
+    /// The content is the ghost of a [`DragSource`](crate::DragSource) being dragged.
+    Dragging,
+    /// The content is the ghost of a drag hovering a [`DropTarget`](crate::DropTarget).
+    OverTarget,
+}
+
+/// The appearance drawn behind the content of a [`MouseLayer`](crate::MouseLayer).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] behind the content.
+    pub background: Option<Background>,
+    /// The [`Border`] around the content.
+    pub border: Border,
+    /// The [`Shadow`] cast by the content.
+    pub shadow: Shadow,
+}
+
+impl Style {
+    /// Returns `true` if the [`Style`] draws nothing.
+    pub(crate) fn is_transparent(&self) -> bool {
+        self.background.is_none()
+            && (self.border.width == 0.0 || self.border.color.a == 0.0)
+            && self.shadow.color.a == 0.0
+    }
+
+    /// Scales the alpha of every color of the [`Style`].
+    pub(crate) fn scale_alpha(self, alpha: f32) -> Self {
+        Self {
+            background: self.background.map(|background| background.scale_alpha(alpha)),
+            border: Border {
+                color: self.border.color.scale_alpha(alpha),
+                ..self.border
+            },
+            shadow: Shadow {
+                color: self.shadow.color.scale_alpha(alpha),
+                ..self.shadow
+            },
+        }
+    }
+}
+
+/// The theme catalog of a [`MouseLayer`](crate::MouseLayer).
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`MouseLayer`](crate::MouseLayer).
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(transparent)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// Draws nothing behind the content. This is the default.
+pub fn transparent<Theme>(_theme: &Theme, _status: Status) -> Style {
+    Style::default()
+}
+
+/// A card lifted above the window, rising further while it is dragged
+/// and outlined while it hovers a drop target.
+pub fn elevated(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let (offset, blur_radius) = match status {
+        Status::Idle => (2.0, 6.0),
+        Status::Moving => (4.0, 10.0),
+        Status::Dragging | Status::OverTarget => (8.0, 18.0),
+    };
+
+    let border_color = match status {
+        Status::OverTarget => palette.primary.strong.color,
+        _ => palette.background.strong.color,
+    };
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+            offset: Vector::new(0.0, offset),
+            blur_radius,
+        },
+    }
+}
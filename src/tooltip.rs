@@ -0,0 +1,105 @@
+//! A tooltip that follows the cursor over a target element.
+use iced::time::Duration;
+use iced::Element;
+
+use crate::{Anchor, Catalog, Follow, MouseLayer, Placement, Transition};
+
+/// An element that shows a tooltip following the cursor while the cursor is over it.
+///
+/// The target is laid out and drawn like any other element; the tooltip is a
+/// [`MouseLayer`] shown [`within`](MouseLayer::within) its bounds.
+pub struct FollowTooltip<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    layer: MouseLayer<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> FollowTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`FollowTooltip`] showing `tooltip` over `target`.
+    ///
+    /// By default the tooltip sits at `(12, 12)` from the cursor and flips
+    /// at the edges of the window.
+    pub fn new(
+        target: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            layer: MouseLayer::new(tooltip)
+                .within(target)
+                .offset(12.0, 12.0)
+                .placement(Placement::Flip),
+        }
+    }
+
+    /// Sets the offset of the tooltip from the cursor position.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.layer = self.layer.offset(x, y);
+        self
+    }
+
+    /// Sets the [`Placement`] used when the tooltip reaches the edge of the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.layer = self.layer.placement(placement);
+        self
+    }
+
+    /// Sets the [`Anchor`] of the tooltip that is placed at the cursor plus the offset.
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.layer = self.layer.anchor(anchor);
+        self
+    }
+
+    /// Sets how the tooltip catches up with the cursor.
+    pub fn follow(mut self, follow: Follow) -> Self {
+        self.layer = self.layer.follow(follow);
+        self
+    }
+
+    /// Sets how long the cursor must rest over the target before the tooltip is shown.
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.layer = self.layer.show_delay(delay);
+        self
+    }
+
+    /// Sets how long the tooltip stays after the cursor leaves the target.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.layer = self.layer.hide_delay(delay);
+        self
+    }
+
+    /// Sets the [`Transition`] played when the tooltip appears and disappears.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.layer = self.layer.transition(transition);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FollowTooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(tooltip: FollowTooltip<'a, Message, Theme, Renderer>) -> Self {
+        tooltip.layer.into()
+    }
+}
+
+/// Creates a new [`FollowTooltip`].
+pub fn follow_tooltip<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FollowTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    FollowTooltip::new(target, tooltip)
+}
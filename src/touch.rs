@@ -0,0 +1,569 @@
+//! Following fingers on touchscreens.
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::mouse;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::time::Instant;
+use iced::touch::{self, Finger};
+use iced::window;
+use iced::{Element, Event, Length, Point, Rectangle, Size, Vector};
+
+use crate::transition::Appearance;
+use crate::{Anchor, Placement, Transition};
+
+/// The finger a [`MouseLayer`](crate::MouseLayer) follows on touchscreens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Touch {
+    /// The finger that has been down the longest. This is the default.
+    #[default]
+    First,
+    /// The finger pressed most recently.
+    Latest,
+    /// The given finger, while it is down.
+    Finger(Finger),
+}
+
+/// The position of the pointer, either the mouse cursor or a finger.
+#[derive(Debug, Default)]
+pub(crate) struct Pointer {
+    /// The fingers that are down, in the order they were pressed.
+    fingers: Vec<(Finger, Point)>,
+    is_touch: bool,
+}
+
+impl Pointer {
+    /// Processes an [`Event`] and returns the position of the pointer, in the coordinates
+    /// given by `translation`.
+    ///
+    /// Without `touch`, the pointer is always the mouse cursor. Otherwise it follows the
+    /// chosen finger after a touch, and the cursor again once the mouse moves.
+    pub fn update(
+        &mut self,
+        touch: Option<Touch>,
+        event: &Event,
+        cursor: mouse::Cursor,
+        translation: Vector,
+    ) -> Option<Point> {
+        let Some(touch) = touch else {
+            return (cursor - translation).position();
+        };
+
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                self.fingers.retain(|(finger, _)| finger != id);
+                self.fingers.push((*id, *position));
+                self.is_touch = true;
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some((_, last)) = self.fingers.iter_mut().find(|(finger, _)| finger == id) {
+                    *last = *position;
+                }
+
+                self.is_touch = true;
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                self.fingers.retain(|(finger, _)| finger != id);
+                self.is_touch = true;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if self.fingers.is_empty() => {
+                self.is_touch = false;
+            }
+            _ => {}
+        }
+
+        if !self.is_touch {
+            return (cursor - translation).position();
+        }
+
+        let finger = match touch {
+            Touch::First => self.fingers.first(),
+            Touch::Latest => self.fingers.last(),
+            Touch::Finger(id) => self.fingers.iter().find(|(finger, _)| *finger == id),
+        };
+
+        finger.map(|(_, position)| *position - translation)
+    }
+
+    /// Returns whether the pointer is a finger.
+    pub fn is_touch(&self) -> bool {
+        self.is_touch
+    }
+}
+
+/// A layer showing a separate element under every finger on a touchscreen.
+///
+/// Each element appears when its finger is pressed and disappears when it is lifted,
+/// playing the [`Transition`]s of the layer.
+pub struct FingerLayer<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    view: Box<dyn Fn(Finger, Point) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+    offset: Vector,
+    anchor: Anchor,
+    placement: Placement,
+    enter: Transition,
+    exit: Transition,
+}
+
+impl<'a, Message, Theme, Renderer> FingerLayer<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`FingerLayer`] showing the element produced by `view` for every
+    /// finger, given its position in window coordinates.
+    pub fn new(view: impl Fn(Finger, Point) -> Element<'a, Message, Theme, Renderer> + 'a) -> Self {
+        Self {
+            view: Box::new(view),
+            elements: Vec::new(),
+            offset: Vector::ZERO,
+            anchor: Anchor::default(),
+            placement: Placement::default(),
+            enter: Transition::NONE,
+            exit: Transition::NONE,
+        }
+    }
+
+    /// Sets the offset of every element from its finger.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Vector::new(x, y);
+        self
+    }
+
+    /// Sets the [`Anchor`] of every element that is placed at its finger plus the offset.
+    pub fn anchor(mut self, anchor: impl Into<Anchor>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Centers every element 48 pixels above its finger, so the finger does not cover it.
+    pub fn lift_above_finger(self) -> Self {
+        self.offset(0.0, -48.0).anchor(Anchor::BOTTOM_CENTER)
+    }
+
+    /// Sets the [`Placement`] used when an element reaches the edge of the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Transition`] played both when an element appears and disappears.
+    pub fn transition(self, transition: Transition) -> Self {
+        self.enter(transition).exit(transition)
+    }
+
+    /// Sets the [`Transition`] played when a finger is pressed.
+    pub fn enter(mut self, transition: Transition) -> Self {
+        self.enter = transition;
+        self
+    }
+
+    /// Sets the [`Transition`] played when a finger is lifted.
+    pub fn exit(mut self, transition: Transition) -> Self {
+        self.exit = transition;
+        self
+    }
+}
+
+/// A finger that is down, or still disappearing after it was lifted.
+struct Ghost {
+    finger: Finger,
+    position: Point,
+    is_lifted: bool,
+    appearance: Appearance,
+    tree: widget::Tree,
+}
+
+/// The fingers on the screen, in the order they were pressed.
+#[derive(Default)]
+struct Fingers {
+    ghosts: Vec<Ghost>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FingerLayer<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Fingers>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Fingers::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut widget::Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::ZERO)
+    }
+
+    fn draw(
+        &self,
+        _tree: &widget::Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        _layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        _translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<Fingers>();
+
+        // Lifted fingers are only dropped here, so elements and fingers stay aligned
+        // for as long as the overlay lives.
+        state
+            .ghosts
+            .retain(|ghost| !ghost.is_lifted || ghost.appearance.is_shown());
+
+        self.elements = state
+            .ghosts
+            .iter_mut()
+            .map(|ghost| {
+                let element = (self.view)(ghost.finger, ghost.position);
+                ghost.tree.diff(&element);
+                element
+            })
+            .collect();
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            elements: &mut self.elements,
+            state,
+            offset: self.offset,
+            anchor: self.anchor,
+            placement: self.placement,
+            enter: self.enter,
+            exit: self.exit,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FingerLayer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(layer: FingerLayer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(layer)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    elements: &'b mut [Element<'a, Message, Theme, Renderer>],
+    state: &'b mut Fingers,
+    offset: Vector,
+    anchor: Anchor,
+    placement: Placement,
+    enter: Transition,
+    exit: Transition,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let children = self
+            .elements
+            .iter_mut()
+            .zip(self.state.ghosts.iter_mut())
+            .map(|(element, ghost)| {
+                let node = element.as_widget_mut().layout(&mut ghost.tree, renderer, &limits);
+                let size = node.size();
+
+                let position = self.placement.position(
+                    ghost.position,
+                    self.offset,
+                    self.anchor.resolve(size),
+                    size,
+                    Rectangle::with_size(bounds),
+                );
+
+                node.move_to(position)
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, children)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        for ((element, ghost), layout) in self
+            .elements
+            .iter()
+            .zip(&self.state.ghosts)
+            .zip(layout.children())
+        {
+            if !ghost.appearance.is_shown() {
+                continue;
+            }
+
+            let bounds = layout.bounds();
+            let pivot = bounds.position() + self.anchor.resolve(bounds.size());
+            let transformation = ghost.appearance.transformation(pivot);
+
+            let style = renderer::Style {
+                text_color: style.text_color.scale_alpha(ghost.appearance.alpha()),
+            };
+
+            renderer.with_transformation(transformation, |renderer| {
+                element.as_widget().draw(
+                    &ghost.tree,
+                    renderer,
+                    theme,
+                    &style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &Rectangle::with_size(Size::INFINITE),
+                );
+            });
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let ghosts = &mut self.state.ghosts;
+
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                // A finger pressed again while its old element disappears gets a new one.
+                if ghosts
+                    .iter()
+                    .any(|ghost| ghost.finger == *id && !ghost.is_lifted)
+                {
+                    return;
+                }
+
+                let mut appearance = Appearance::default();
+                appearance.go(true, self.enter, Instant::now());
+
+                ghosts.push(Ghost {
+                    finger: *id,
+                    position: *position,
+                    is_lifted: false,
+                    appearance,
+                    tree: widget::Tree::empty(),
+                });
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(ghost) = ghosts
+                    .iter_mut()
+                    .find(|ghost| ghost.finger == *id && !ghost.is_lifted)
+                {
+                    ghost.position = *position;
+                    shell.request_redraw();
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if let Some(ghost) = ghosts
+                    .iter_mut()
+                    .find(|ghost| ghost.finger == *id && !ghost.is_lifted)
+                {
+                    ghost.is_lifted = true;
+                    ghost.appearance.go(false, self.exit, Instant::now());
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let mut is_animating = false;
+
+                for ghost in ghosts.iter_mut() {
+                    is_animating |= ghost.appearance.tick(*now);
+                }
+
+                if is_animating {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Creates a new [`FingerLayer`] showing the element produced by `view` for every finger.
+pub fn finger_layer<'a, Message, Theme, Renderer>(
+    view: impl Fn(Finger, Point) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> FingerLayer<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    FingerLayer::new(view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUSE: Point = Point::new(1.0, 1.0);
+
+    fn pressed(id: u64, x: f32, y: f32) -> Event {
+        Event::Touch(touch::Event::FingerPressed {
+            id: Finger(id),
+            position: Point::new(x, y),
+        })
+    }
+
+    fn moved(id: u64, x: f32, y: f32) -> Event {
+        Event::Touch(touch::Event::FingerMoved {
+            id: Finger(id),
+            position: Point::new(x, y),
+        })
+    }
+
+    fn lifted(id: u64) -> Event {
+        Event::Touch(touch::Event::FingerLifted {
+            id: Finger(id),
+            position: Point::ORIGIN,
+        })
+    }
+
+    fn cursor_moved() -> Event {
+        Event::Mouse(mouse::Event::CursorMoved { position: MOUSE })
+    }
+
+    /// Feeds every event to a new [`Pointer`] following `touch`, returning every position.
+    fn follow(touch: Touch, events: &[Event]) -> Vec<Option<Point>> {
+        let mut pointer = Pointer::default();
+
+        events
+            .iter()
+            .map(|event| {
+                pointer.update(
+                    Some(touch),
+                    event,
+                    mouse::Cursor::Available(MOUSE),
+                    Vector::ZERO,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_follows_the_oldest_finger() {
+        let positions = follow(
+            Touch::First,
+            &[pressed(1, 10.0, 10.0), pressed(2, 20.0, 20.0), moved(1, 15.0, 15.0), lifted(1)],
+        );
+
+        assert_eq!(
+            positions,
+            [
+                Some(Point::new(10.0, 10.0)),
+                Some(Point::new(10.0, 10.0)),
+                Some(Point::new(15.0, 15.0)),
+                Some(Point::new(20.0, 20.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn latest_follows_the_newest_finger() {
+        let positions = follow(
+            Touch::Latest,
+            &[pressed(1, 10.0, 10.0), pressed(2, 20.0, 20.0), moved(1, 15.0, 15.0), lifted(2)],
+        );
+
+        assert_eq!(
+            positions,
+            [
+                Some(Point::new(10.0, 10.0)),
+                Some(Point::new(20.0, 20.0)),
+                Some(Point::new(20.0, 20.0)),
+                Some(Point::new(15.0, 15.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn finger_follows_only_its_finger() {
+        let positions = follow(
+            Touch::Finger(Finger(2)),
+            &[pressed(1, 10.0, 10.0), pressed(2, 20.0, 20.0), moved(2, 25.0, 25.0), lifted(2)],
+        );
+
+        assert_eq!(
+            positions,
+            [None, Some(Point::new(20.0, 20.0)), Some(Point::new(25.0, 25.0)), None]
+        );
+    }
+
+    #[test]
+    fn no_position_after_the_last_finger_lifts() {
+        let positions = follow(Touch::First, &[pressed(1, 10.0, 10.0), lifted(1)]);
+
+        assert_eq!(positions, [Some(Point::new(10.0, 10.0)), None]);
+    }
+
+    #[test]
+    fn mouse_takes_over_once_no_finger_is_down() {
+        let positions = follow(
+            Touch::First,
+            &[pressed(1, 10.0, 10.0), cursor_moved(), lifted(1), cursor_moved()],
+        );
+
+        assert_eq!(
+            positions,
+            [Some(Point::new(10.0, 10.0)), Some(Point::new(10.0, 10.0)), None, Some(MOUSE)]
+        );
+    }
+
+    #[test]
+    fn without_touch_the_pointer_is_the_mouse() {
+        let mut pointer = Pointer::default();
+
+        let position = pointer.update(
+            None,
+            &pressed(1, 10.0, 10.0),
+            mouse::Cursor::Available(MOUSE),
+            Vector::ZERO,
+        );
+
+        assert_eq!(position, Some(MOUSE));
+    }
+}
@@ -0,0 +1,113 @@
+//! A fading trail behind the content.
+use iced::time::{Duration, Instant};
+use iced::{Color, Point};
+
+use std::collections::VecDeque;
+
+/// A trail left behind the content of a [`MouseLayer`](crate::MouseLayer) as it moves.
+///
+/// The trail follows the recent path of the content, bounded both by a number of
+/// positions and by their age.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trail {
+    pub(crate) kind: Kind,
+    pub(crate) length: usize,
+    pub(crate) duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Copies,
+    Line { width: f32, color: Color },
+}
+
+impl Trail {
+    /// Draws fading copies of the content at its recent positions.
+    ///
+    /// Copies fade like [`Transition::fade`](crate::Transition::fade).
+    pub fn copies() -> Self {
+        Self {
+            kind: Kind::Copies,
+            length: 8,
+            duration: Duration::from_millis(300),
+        }
+    }
+
+    /// Draws a fading line of the given width and color along the recent path of the cursor.
+    pub fn line(width: f32, color: impl Into<Color>) -> Self {
+        Self {
+            kind: Kind::Line {
+                width,
+                color: color.into(),
+            },
+            length: 64,
+            duration: Duration::from_millis(300),
+        }
+    }
+
+    /// Sets the maximum number of positions kept in the trail.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets how long a position stays in the trail.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// The recent positions of the content, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct History {
+    samples: VecDeque<(Instant, Point)>,
+    now: Option<Instant>,
+}
+
+impl History {
+    /// Records the position of the content at the frame at `now`, dropping the
+    /// positions that fell out of the [`Trail`]. Returns `true` while the trail is visible.
+    pub fn record(&mut self, trail: Trail, position: Option<Point>, now: Instant) -> bool {
+        self.now = Some(now);
+
+        if let Some(position) = position
+            && self.samples.back().is_none_or(|(_, last)| *last != position)
+        {
+            self.samples.push_back((now, position));
+        }
+
+        while self.samples.len() > trail.length.max(1)
+            || self.samples.front().is_some_and(|(at, _)| {
+                now.saturating_duration_since(*at) > trail.duration
+            })
+        {
+            let _ = self.samples.pop_front();
+        }
+
+        self.samples.len() > 1
+    }
+
+    /// Forgets every position.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the recorded positions with their strength, from `0.0` (about to
+    /// disappear) to `1.0` (the latest), oldest first.
+    pub fn samples(&self, trail: Trail) -> impl Iterator<Item = (Point, f32)> + '_ {
+        let now = self.now;
+        let count = self.samples.len() as f32;
+
+        self.samples.iter().enumerate().map(move |(index, (at, position))| {
+            let age = now
+                .map(|now| now.saturating_duration_since(*at).as_secs_f32())
+                .unwrap_or_default();
+
+            let by_age = 1.0 - age / trail.duration.as_secs_f32().max(f32::EPSILON);
+            let by_rank = (index + 1) as f32 / count;
+
+            (*position, (by_age * by_rank).clamp(0.0, 1.0))
+        })
+    }
+}
@@ -0,0 +1,148 @@
+//! Appear and disappear animations.
+use iced::animation::{Animation, Easing};
+use iced::time::{Duration, Instant};
+use iced::{Point, Transformation, Vector};
+
+/// An animation played when the content of a [`MouseLayer`](crate::MouseLayer)
+/// appears or disappears.
+///
+/// The effects describe the hidden end of the animation; the content animates from
+/// them when it appears and back to them when it disappears.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    duration: Duration,
+    fade: bool,
+    scale: f32,
+    slide: Vector,
+}
+
+impl Transition {
+    /// No animation: the content appears and disappears instantly.
+    pub const NONE: Self = Self {
+        duration: Duration::ZERO,
+        fade: false,
+        scale: 1.0,
+        slide: Vector::ZERO,
+    };
+
+    /// Creates a [`Transition`] of the given duration, without any effect.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::NONE
+        }
+    }
+
+    /// Fades the content in and out.
+    ///
+    /// This fades everything the layer draws itself, i.e. its style, guides and trail line,
+    /// and the inherited text color. iced renderers have no group opacity, so child widgets
+    /// that set their own colors are not faded.
+    pub fn fade(mut self) -> Self {
+        self.fade = true;
+        self
+    }
+
+    /// Scales the content from the given factor, around the pivot of the layer.
+    pub fn scale(mut self, from: f32) -> Self {
+        self.scale = from;
+        self
+    }
+
+    /// Slides the content from the given offset.
+    pub fn slide(mut self, x: f32, y: f32) -> Self {
+        self.slide = Vector::new(x, y);
+        self
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// How much the content is shown while it appears or disappears.
+#[derive(Debug, Clone)]
+pub(crate) struct Appearance {
+    animation: Animation<bool>,
+    transition: Transition,
+    now: Option<Instant>,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            animation: Animation::new(false),
+            transition: Transition::NONE,
+            now: None,
+        }
+    }
+}
+
+impl Appearance {
+    /// Starts showing or hiding the content at the given time with the given [`Transition`].
+    pub fn go(&mut self, is_visible: bool, transition: Transition, at: Instant) {
+        self.transition = transition;
+
+        self.animation = if transition.duration.is_zero() {
+            Animation::new(is_visible)
+        } else {
+            self.animation
+                .clone()
+                .easing(Easing::EaseOutCubic)
+                .duration(transition.duration)
+                .go(is_visible, at)
+        };
+    }
+
+    /// Records the time of the current frame. Returns `true` while animating.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        self.now = Some(now);
+        self.animation.is_animating(now)
+    }
+
+    /// Returns whether any part of the content is shown.
+    pub fn is_shown(&self) -> bool {
+        self.animation.value() || self.progress() > 0.0
+    }
+
+    /// Returns how much the content is shown, from `0.0` (hidden) to `1.0` (fully shown).
+    pub fn progress(&self) -> f32 {
+        match self.now {
+            Some(now) => self.animation.interpolate(0.0, 1.0, now),
+            None if self.animation.value() => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Returns the alpha multiplier of the content.
+    pub fn alpha(&self) -> f32 {
+        if self.transition.fade {
+            self.progress()
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the scale multiplier of the content.
+    pub fn scale(&self) -> f32 {
+        let progress = self.progress();
+
+        self.transition.scale + (1.0 - self.transition.scale) * progress
+    }
+
+    /// Returns the translation of the content.
+    pub fn slide(&self) -> Vector {
+        self.transition.slide * (1.0 - self.progress())
+    }
+
+    /// Returns the [`Transformation`] scaling the content around `pivot` and sliding it.
+    pub fn transformation(&self, pivot: Point) -> Transformation {
+        let slide = self.slide();
+
+        Transformation::translate(pivot.x + slide.x, pivot.y + slide.y)
+            * Transformation::scale(self.scale())
+            * Transformation::translate(-pivot.x, -pivot.y)
+    }
+}
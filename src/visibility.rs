@@ -0,0 +1,122 @@
+//! Declarative show/hide conditions.
+use iced::advanced::Shell;
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::touch;
+use iced::window;
+use iced::{Event, Point, Rectangle};
+
+/// The conditions under which the content of a [`MouseLayer`](crate::MouseLayer) is shown.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Visibility {
+    pub button: Option<mouse::Button>,
+    pub show_delay: Duration,
+    pub hide_delay: Duration,
+    /// Hides the content regardless of the other conditions.
+    pub is_hidden: bool,
+}
+
+/// Whether the content is currently shown, and since when the conditions disagree with it.
+#[derive(Debug, Default)]
+pub(crate) struct Toggle {
+    is_visible: bool,
+    is_pressed: bool,
+    pending: Option<Pending>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Pending {
+    /// The conditions changed, but no frame has been seen since.
+    Changed,
+    /// The conditions changed at the given frame.
+    Since(Instant),
+}
+
+impl Toggle {
+    /// Returns whether the content is shown.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Processes an [`Event`], showing or hiding the content once the conditions
+    /// have held for long enough.
+    ///
+    /// `region` is the area the cursor must be inside of, in the same coordinates as
+    /// `cursor`. Returns `true` if the content was shown or hidden.
+    pub fn update<Message>(
+        &mut self,
+        visibility: Visibility,
+        event: &Event,
+        cursor: Option<Point>,
+        region: Option<Rectangle>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if let Some(button) = visibility.button {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(pressed)) if *pressed == button => {
+                    self.is_pressed = true;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(released)) if *released == button => {
+                    self.is_pressed = false;
+                }
+                // A finger on a touchscreen acts as the left button.
+                Event::Touch(touch::Event::FingerPressed { .. }) if button == mouse::Button::Left => {
+                    self.is_pressed = true;
+                }
+                Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                    if button == mouse::Button::Left =>
+                {
+                    self.is_pressed = false;
+                }
+                _ => {}
+            }
+        }
+
+        let should_show = !visibility.is_hidden
+            && cursor.is_some_and(|cursor| {
+                region.is_none_or(|region| region.contains(cursor))
+                    && (visibility.button.is_none() || self.is_pressed)
+            });
+
+        if should_show == self.is_visible {
+            self.pending = None;
+            return false;
+        }
+
+        let delay = if should_show {
+            visibility.show_delay
+        } else {
+            visibility.hide_delay
+        };
+
+        if delay.is_zero() {
+            self.pending = None;
+            self.is_visible = should_show;
+            return true;
+        }
+
+        match (self.pending, event) {
+            (Some(Pending::Since(since)), Event::Window(window::Event::RedrawRequested(now)))
+                if now.saturating_duration_since(since) >= delay =>
+            {
+                self.pending = None;
+                self.is_visible = should_show;
+                true
+            }
+            (Some(Pending::Since(since)), _) => {
+                shell.request_redraw_at(since + delay);
+                false
+            }
+            (_, Event::Window(window::Event::RedrawRequested(now))) => {
+                self.pending = Some(Pending::Since(*now));
+                shell.request_redraw_at(*now + delay);
+                false
+            }
+            _ => {
+                self.pending = Some(Pending::Changed);
+                shell.request_redraw();
+                false
+            }
+        }
+    }
+}